@@ -0,0 +1,111 @@
+//! Peak heap usage and allocation counts via a wrapping global allocator.
+//!
+//! Enabled with `cargo run --release --features track-alloc`. Without the
+//! feature the system allocator is used untouched and `peak_during` and
+//! `allocations_during` report `None`, so benchmarks can call into this
+//! module unconditionally.
+
+/// Returns true when the counting allocator is compiled in.
+pub fn enabled() -> bool {
+    cfg!(feature = "track-alloc")
+}
+
+#[cfg(feature = "track-alloc")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Bytes currently allocated.
+    pub static LIVE: AtomicUsize = AtomicUsize::new(0);
+    /// High-water mark of `LIVE` since the last reset.
+    pub static PEAK: AtomicUsize = AtomicUsize::new(0);
+    /// alloc, alloc_zeroed and realloc calls.
+    pub static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    fn grow(bytes: usize) {
+        let live = LIVE.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn shrink(bytes: usize) {
+        LIVE.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    struct CountingAlloc;
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            shrink(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                if new_size >= layout.size() {
+                    grow(new_size - layout.size());
+                } else {
+                    shrink(layout.size() - new_size);
+                }
+            }
+            new
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAlloc = CountingAlloc;
+}
+
+/// Runs `f` and returns how far live heap bytes rose above their starting
+/// level while it ran, or `None` when tracking is disabled.
+pub fn peak_during<R>(f: impl FnOnce() -> R) -> (R, Option<usize>) {
+    #[cfg(feature = "track-alloc")]
+    {
+        use std::sync::atomic::Ordering;
+        let base = counting::LIVE.load(Ordering::Relaxed);
+        counting::PEAK.store(base, Ordering::Relaxed);
+        let result = f();
+        let peak = counting::PEAK.load(Ordering::Relaxed);
+        (result, Some(peak.saturating_sub(base)))
+    }
+    #[cfg(not(feature = "track-alloc"))]
+    {
+        (f(), None)
+    }
+}
+
+/// Runs `f` and returns how many allocator calls (alloc, alloc_zeroed,
+/// realloc) it made on any thread, or `None` when tracking is disabled.
+pub fn allocations_during<R>(f: impl FnOnce() -> R) -> (R, Option<usize>) {
+    #[cfg(feature = "track-alloc")]
+    {
+        use std::sync::atomic::Ordering;
+        let before = counting::ALLOCATIONS.load(Ordering::Relaxed);
+        let result = f();
+        let after = counting::ALLOCATIONS.load(Ordering::Relaxed);
+        (result, Some(after - before))
+    }
+    #[cfg(not(feature = "track-alloc"))]
+    {
+        (f(), None)
+    }
+}
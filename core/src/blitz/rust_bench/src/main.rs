@@ -5,9 +5,17 @@
 //!
 //! Run with: cargo run --release
 
+mod alloc_track;
+mod maybe_par;
+mod topology;
+
 use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::collections::{HashMap, HashSet};
 use std::hint::black_box;
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 // ============================================================================
 // Configuration
@@ -18,6 +26,672 @@ const WARMUP_ITERATIONS: usize = 3;
 
 const SIZES: [usize; 5] = [1_000, 10_000, 100_000, 1_000_000, 10_000_000];
 
+// ============================================================================
+// Command-line options
+// ============================================================================
+
+const USAGE: &str = "Usage: blitz_bench [--threads <N[,N...]>] [--work-ns <N>] [--work-stats]
+                   [--find-breakeven]
+       --threads N        pin rayon's global pool to N threads
+       --threads N,M,...  sweep: run each parallel benchmark in an N-, M-, ... thread pool
+       --work-ns N        add ~N ns of calibrated compute to the map, for and filter bodies
+       --work-stats       after sum, map and uneven workload, show per-thread work shares
+       --find-breakeven   only search the input size where par beats seq by 10% (sum, map, sort)";
+
+struct Options {
+    /// One count pins the global pool; several run a sweep over dedicated
+    /// pools; empty runs once on rayon's default global pool.
+    threads: Vec<usize>,
+    /// Extra compute per element in the map, for and filter bodies.
+    work_ns: u64,
+    /// Re-run selected benchmarks once, untimed, with per-thread tallies.
+    work_stats: bool,
+    /// Run only the sequential/parallel crossover search.
+    find_breakeven: bool,
+}
+
+impl Options {
+    fn from_args() -> Self {
+        let mut opts = Options {
+            threads: Vec::new(),
+            work_ns: 0,
+            work_stats: false,
+            find_breakeven: false,
+        };
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--threads" => {
+                    let Some(list) = args.next() else {
+                        usage_error("--threads expects a comma-separated list of counts");
+                    };
+                    opts.threads = list
+                        .split(',')
+                        .map(|t| match t.trim().parse() {
+                            Ok(n) if n > 0 => n,
+                            _ => usage_error(&format!("invalid thread count '{}'", t)),
+                        })
+                        .collect();
+                }
+                "--work-ns" => {
+                    let value = args.next().unwrap_or_default();
+                    opts.work_ns = value.parse().unwrap_or_else(|_| {
+                        usage_error(&format!("invalid --work-ns value '{}'", value))
+                    });
+                }
+                "--work-stats" => opts.work_stats = true,
+                "--find-breakeven" => opts.find_breakeven = true,
+                other => usage_error(&format!("Unknown option: {}", other)),
+            }
+        }
+
+        opts
+    }
+}
+
+fn usage_error(msg: &str) -> ! {
+    eprintln!("{}", msg);
+    eprintln!("{}", USAGE);
+    std::process::exit(2);
+}
+
+static OPTIONS: OnceLock<Options> = OnceLock::new();
+
+fn options() -> &'static Options {
+    OPTIONS.get_or_init(Options::from_args)
+}
+
+// ============================================================================
+// Thread pools
+// ============================================================================
+
+/// Pins the global pool for `--threads N`; must run before any parallel work.
+fn init_global_pool() {
+    let Some(threads) = pinned_threads() else {
+        return;
+    };
+    if let Err(e) = ThreadPoolBuilder::new().num_threads(threads).build_global() {
+        eprintln!(
+            "--threads {}: cannot configure the global rayon pool ({}); \
+             it was already initialized with {} threads before the flag was applied",
+            threads,
+            e,
+            rayon::current_num_threads()
+        );
+        std::process::exit(1);
+    }
+}
+
+/// The single `--threads` count, which pins the global pool.
+fn pinned_threads() -> Option<usize> {
+    match options().threads.as_slice() {
+        &[threads] => Some(threads),
+        _ => None,
+    }
+}
+
+static POOLS: OnceLock<Vec<ThreadPool>> = OnceLock::new();
+
+/// One dedicated pool per `--threads` entry when sweeping two or more counts.
+fn pools() -> &'static [ThreadPool] {
+    POOLS.get_or_init(|| {
+        if pinned_threads().is_some() {
+            return Vec::new();
+        }
+        options()
+            .threads
+            .iter()
+            .map(|&t| {
+                ThreadPoolBuilder::new()
+                    .num_threads(t)
+                    .build()
+                    .unwrap_or_else(|e| {
+                        eprintln!("failed to build a {}-thread pool: {}", t, e);
+                        std::process::exit(1);
+                    })
+            })
+            .collect()
+    })
+}
+
+/// Runs `f` inside every sweep pool, or once on the global pool without a sweep.
+///
+/// Returns each result alongside the thread count it ran with.
+fn on_each_pool<R: Send>(mut f: impl FnMut() -> R + Send) -> Vec<(usize, R)> {
+    if pools().is_empty() {
+        return vec![(rayon::current_num_threads(), f())];
+    }
+    pools()
+        .iter()
+        .map(|pool| (pool.current_num_threads(), pool.install(&mut f)))
+        .collect()
+}
+
+// ============================================================================
+// Timing helpers
+// ============================================================================
+
+/// Average wall time of `f` in ms over `ITERATIONS`, after `WARMUP_ITERATIONS`.
+fn time_ms(mut f: impl FnMut()) -> f64 {
+    for _ in 0..WARMUP_ITERATIONS {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64
+}
+
+/// Per-iteration wall times, for benchmarks that report spread as well as
+/// the mean.
+struct Stats {
+    min_ms: f64,
+    max_ms: f64,
+    total_ms: f64,
+    total_sq: f64,
+    count: usize,
+}
+
+impl Stats {
+    fn new() -> Self {
+        Self {
+            min_ms: f64::MAX,
+            max_ms: 0.0,
+            total_ms: 0.0,
+            total_sq: 0.0,
+            count: 0,
+        }
+    }
+
+    fn add(&mut self, ms: f64) {
+        self.min_ms = self.min_ms.min(ms);
+        self.max_ms = self.max_ms.max(ms);
+        self.total_ms += ms;
+        self.total_sq += ms * ms;
+        self.count += 1;
+    }
+
+    fn mean_ms(&self) -> f64 {
+        self.total_ms / self.count.max(1) as f64
+    }
+
+    /// Sample standard deviation.
+    fn stddev_ms(&self) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        let n = self.count as f64;
+        let var = (self.total_sq - self.total_ms * self.total_ms / n) / (n - 1.0);
+        var.max(0.0).sqrt()
+    }
+
+    /// `<mean>ms (sd <x>, cv <y>%, min <a>, max <b>)`.
+    fn summary(&self) -> String {
+        let mean = self.mean_ms();
+        format!(
+            "{:.3}ms (sd {:.3}, cv {:.1}%, min {:.3}, max {:.3})",
+            mean,
+            self.stddev_ms(),
+            self.stddev_ms() / mean * 100.0,
+            self.min_ms,
+            self.max_ms
+        )
+    }
+}
+
+/// Like `time_ms`, but keeps every iteration's time.
+fn time_stats(mut f: impl FnMut()) -> Stats {
+    for _ in 0..WARMUP_ITERATIONS {
+        f();
+    }
+    let mut stats = Stats::new();
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        f();
+        stats.add(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    stats
+}
+
+/// `time_ms` of a parallel body on every pool in the sweep.
+fn time_par_ms(mut f: impl FnMut() + Send) -> Vec<(usize, f64)> {
+    on_each_pool(|| time_ms(&mut f))
+}
+
+/// Like `time_ms`, but each iteration gets fresh input from `setup` and only
+/// `f` is timed; the input is dropped outside the timed region as well.
+fn time_with_setup_ms<T>(mut setup: impl FnMut() -> T, mut f: impl FnMut(&mut T)) -> f64 {
+    for _ in 0..WARMUP_ITERATIONS {
+        f(&mut setup());
+    }
+    let mut total = 0.0;
+    for _ in 0..ITERATIONS {
+        let mut input = setup();
+        let start = Instant::now();
+        f(&mut input);
+        total += start.elapsed().as_secs_f64();
+        drop(input);
+    }
+    total * 1000.0 / ITERATIONS as f64
+}
+
+/// `time_with_setup_ms` of a parallel body on every pool in the sweep.
+fn time_par_with_setup_ms<T>(
+    mut setup: impl FnMut() -> T + Send,
+    mut f: impl FnMut(&mut T) + Send,
+) -> Vec<(usize, f64)> {
+    on_each_pool(|| time_with_setup_ms(&mut setup, &mut f))
+}
+
+/// Like `time_with_setup_ms`, but after the usual warmup repeats only until
+/// `PROBE_BUDGET_MS` of timed work or `PROBE_MAX_ITERATIONS` runs, so a
+/// probe costs about the same at every input size.
+fn time_adaptive_with_setup_ms<T>(mut setup: impl FnMut() -> T, mut f: impl FnMut(&mut T)) -> f64 {
+    for _ in 0..WARMUP_ITERATIONS {
+        f(&mut setup());
+    }
+    let mut total = 0.0;
+    let mut runs = 0;
+    while total * 1000.0 < PROBE_BUDGET_MS && runs < PROBE_MAX_ITERATIONS {
+        let mut input = setup();
+        let start = Instant::now();
+        f(&mut input);
+        total += start.elapsed().as_secs_f64();
+        drop(input);
+        runs += 1;
+    }
+    total * 1000.0 / runs as f64
+}
+
+/// `time_adaptive_with_setup_ms` without per-run input.
+fn time_adaptive_ms(mut f: impl FnMut()) -> f64 {
+    time_adaptive_with_setup_ms(|| (), |_| f())
+}
+
+/// Rate in `unit` for the sequential run and each parallel run, given how
+/// many units one run processes, e.g. `(GB/s: seq 20.1, par 25.4)`.
+fn rate_note(unit: &str, per_run: f64, seq_ms: f64, par: &[(usize, f64)]) -> String {
+    let rate = |ms: f64| per_run / (ms / 1000.0);
+    let mut note = format!("({}: seq {:.1}", unit, rate(seq_ms));
+    for &(threads, par_ms) in par {
+        if pools().is_empty() {
+            note.push_str(&format!(", par {:.1}", rate(par_ms)));
+        } else {
+            note.push_str(&format!(", {}T {:.1}", threads, rate(par_ms)));
+        }
+    }
+    note.push(')');
+    note
+}
+
+/// Nanoseconds per element for the sequential run and each parallel run,
+/// e.g. `(ns/elem: seq 12.3, par 4.5)`.
+fn per_elem_note(n: usize, seq_ms: f64, par: &[(usize, f64)]) -> String {
+    let ns = |ms: f64| ms * 1e6 / n as f64;
+    let mut note = format!("(ns/elem: seq {:.1}", ns(seq_ms));
+    for &(threads, par_ms) in par {
+        if pools().is_empty() {
+            note.push_str(&format!(", par {:.1}", ns(par_ms)));
+        } else {
+            note.push_str(&format!(", {}T {:.1}", threads, ns(par_ms)));
+        }
+    }
+    note.push(')');
+    note
+}
+
+/// Memory traffic of a bandwidth-bound benchmark, so its runs can be
+/// reported in GB/s and counted towards the suite's peak.
+struct Bandwidth {
+    label: &'static str,
+    /// Bytes read plus bytes written per element.
+    bytes_per_elem: usize,
+}
+
+/// Highest GB/s reported by any `Bandwidth::note`, with where it was seen.
+static PEAK_BANDWIDTH: Mutex<Option<(f64, String)>> = Mutex::new(None);
+
+/// Working sets smaller than this may be served from cache, so their rates
+/// are printed but left out of `PEAK_BANDWIDTH`.
+const PEAK_MIN_BYTES: usize = 64 * 1024 * 1024;
+
+impl Bandwidth {
+    /// `rate_note` in GB/s for a run over `n` elements. Rates from working
+    /// sets of at least `PEAK_MIN_BYTES` are also offered to `PEAK_BANDWIDTH`.
+    fn note(&self, n: usize, seq_ms: f64, par: &[(usize, f64)]) -> String {
+        let bytes = n * self.bytes_per_elem;
+        let gb = bytes as f64 / 1e9;
+        if bytes >= PEAK_MIN_BYTES {
+            let mut runs = vec![("seq".to_string(), seq_ms)];
+            for &(threads, ms) in par {
+                let run = if pools().is_empty() {
+                    "par".to_string()
+                } else {
+                    format!("{}T", threads)
+                };
+                runs.push((run, ms));
+            }
+            let mut peak = PEAK_BANDWIDTH.lock().unwrap();
+            for (run, ms) in runs {
+                let rate = gb / (ms / 1000.0);
+                if peak.as_ref().is_none_or(|(best, _)| rate > *best) {
+                    *peak = Some((rate, format!("{}, N={}, {}", self.label, n, run)));
+                }
+            }
+        }
+        rate_note("GB/s", gb, seq_ms, par)
+    }
+}
+
+/// Closing line naming the best GB/s of the run, a rough measure of the
+/// machine's practical memory bandwidth.
+fn print_peak_bandwidth() {
+    if let Some((rate, seen)) = PEAK_BANDWIDTH.lock().unwrap().as_ref() {
+        println!("Peak bandwidth: {:.1} GB/s ({})", rate, seen);
+    }
+}
+
+/// The manual-split baseline for each parallel run, with its speedup over
+/// seq, e.g. `(scoped: 4.123ms 1.20x)`.
+fn scoped_note(seq_ms: f64, scoped: &[(usize, f64)]) -> String {
+    let mut parts = Vec::new();
+    for &(threads, ms) in scoped {
+        if pools().is_empty() {
+            parts.push(format!("{:.3}ms {:.2}x", ms, seq_ms / ms));
+        } else {
+            parts.push(format!("{}T {:.3}ms {:.2}x", threads, ms, seq_ms / ms));
+        }
+    }
+    format!("(scoped: {})", parts.join(", "))
+}
+
+/// Parallel efficiency below this fraction is flagged on result lines; it
+/// usually means the benchmark is bandwidth-bound or split too finely.
+const LOW_EFFICIENCY: f64 = 0.30;
+
+/// Speedup divided by the pool's thread count, as a percentage, e.g.
+/// `45% eff`, with a trailing `!` when it is under `LOW_EFFICIENCY`.
+fn efficiency(speedup: f64, threads: usize) -> String {
+    let eff = speedup / threads as f64;
+    let flag = if eff < LOW_EFFICIENCY { "!" } else { " " };
+    format!("{:>3.0}% eff{}", eff * 100.0, flag)
+}
+
+/// Formats a result line: the classic seq/par/speedup columns without a
+/// sweep, or one `<threads>T <ms> <speedup>` column per pool with one. Each
+/// speedup is followed by its efficiency for the pool it ran on.
+/// With `--threads N` the effective thread count is appended.
+fn speedup_line(label: &str, seq_ms: f64, par: &[(usize, f64)]) -> String {
+    if pools().is_empty() {
+        let (threads, par_ms) = par[0];
+        let speedup = seq_ms / par_ms;
+        let mut line = format!(
+            "  {:<14} seq {:>8.3}ms   par {:>8.3}ms   {:.2}x speedup {}",
+            label,
+            seq_ms,
+            par_ms,
+            speedup,
+            efficiency(speedup, threads)
+        );
+        if pinned_threads().is_some() {
+            line.push_str(&format!("   [{}T]", threads));
+        }
+        return line;
+    }
+    let mut line = format!("  {:<14} seq {:>8.3}ms", label, seq_ms);
+    for &(threads, par_ms) in par {
+        let speedup = seq_ms / par_ms;
+        line.push_str(&format!(
+            "   {:>2}T {:>8.3}ms {:>5.2}x {}",
+            threads,
+            par_ms,
+            speedup,
+            efficiency(speedup, threads)
+        ));
+    }
+    line
+}
+
+// ============================================================================
+// Work distribution (--work-stats)
+// ============================================================================
+
+thread_local! {
+    /// Elements processed and busy nanoseconds on this thread since the
+    /// last `take_work`.
+    static WORK: std::cell::Cell<(u64, u64)> = const { std::cell::Cell::new((0, 0)) };
+}
+
+/// One split's element count and start time, charged to the thread that
+/// ran the split when it is dropped at the split's end.
+struct SplitTally {
+    start: Instant,
+    count: u64,
+}
+
+impl Drop for SplitTally {
+    fn drop(&mut self) {
+        let ns = self.start.elapsed().as_nanos() as u64;
+        WORK.with(|w| {
+            let (elems, busy) = w.get();
+            w.set((elems + self.count, busy + ns));
+        });
+    }
+}
+
+/// `iter` unchanged, except that every split's element count and elapsed
+/// time are charged to the thread that ran it, so the timed pipeline
+/// (sum, collect, ...) can be chained on and tallied as is. Tallying is per
+/// split rather than per element, so the overhead is one clock read pair
+/// per split.
+fn tallied<I: IndexedParallelIterator>(iter: I) -> impl IndexedParallelIterator<Item = I::Item> {
+    iter.map_init(
+        || SplitTally {
+            start: Instant::now(),
+            count: 0,
+        },
+        |tally, item| {
+            tally.count += 1;
+            item
+        },
+    )
+}
+
+/// Reads and resets this thread's tally.
+fn take_work() -> (u64, u64) {
+    WORK.with(|w| w.replace((0, 0)))
+}
+
+/// With `--work-stats`, runs `body` (built on `tallied`) once in each pool
+/// and prints min/median/max per-thread share of elements and busy time.
+/// Without the flag nothing runs, so timed results are never affected.
+fn print_work_stats(body: impl Fn() + Sync + Send) {
+    if !options().work_stats {
+        return;
+    }
+    let per_pool = on_each_pool(|| {
+        rayon::broadcast(|_| take_work());
+        body();
+        rayon::broadcast(|_| take_work())
+    });
+    for (threads, tallies) in per_pool {
+        let shares = |pick: fn(&(u64, u64)) -> u64| {
+            let total = tallies.iter().map(pick).sum::<u64>().max(1) as f64;
+            let mut pct: Vec<f64> = tallies
+                .iter()
+                .map(|t| pick(t) as f64 / total * 100.0)
+                .collect();
+            pct.sort_by(f64::total_cmp);
+            format!(
+                "{:.1}/{:.1}/{:.1}%",
+                pct[0],
+                pct[pct.len() / 2],
+                pct[pct.len() - 1]
+            )
+        };
+        println!(
+            "    work ({}T, min/med/max share): elements {}, busy {}",
+            threads,
+            shares(|t| t.0),
+            shares(|t| t.1)
+        );
+    }
+}
+
+// ============================================================================
+// Per-element compute (--work-ns)
+// ============================================================================
+
+static WORK_ROUNDS: OnceLock<usize> = OnceLock::new();
+
+/// `synthetic_work` rounds that take about `--work-ns` per element, or 0.
+fn work_rounds() -> usize {
+    *WORK_ROUNDS.get_or_init(|| calibrate_rounds(options().work_ns))
+}
+
+/// Label for a benchmark whose body `--work-ns` replaces: `name(body):`, or
+/// `name(~Nns):` with the injected cost.
+fn work_label(name: &str, body: &str) -> String {
+    match options().work_ns {
+        0 => format!("{}({}):", name, body),
+        ns => format!("{}(~{}ns):", name, ns),
+    }
+}
+
+/// Finds the round count whose per-call cost is `target_ns` when called on
+/// independent inputs in a loop, the way the benchmark bodies call it. Short
+/// chains overlap across calls, so this is refined against measured
+/// throughput rather than derived from the latency of one long chain.
+fn calibrate_rounds(target_ns: u64) -> usize {
+    if target_ns == 0 {
+        return 0;
+    }
+    let calls = (20_000_000 / target_ns).clamp(1_000, 1_000_000) as i64;
+    let mut rounds = (target_ns as usize / 2).max(1);
+    for _ in 0..4 {
+        let start = Instant::now();
+        let mut acc = 0i64;
+        for i in 0..calls {
+            acc = acc.wrapping_add(synthetic_work(i, rounds));
+        }
+        black_box(acc);
+        let ns = start.elapsed().as_nanos() as f64 / calls as f64;
+        rounds = ((rounds as f64 * target_ns as f64 / ns).round() as usize).max(1);
+    }
+    rounds
+}
+
+// ============================================================================
+// Break-even search (--find-breakeven)
+// ============================================================================
+
+/// Timed work per measurement at each probe size.
+const PROBE_BUDGET_MS: f64 = 20.0;
+const PROBE_MAX_ITERATIONS: usize = 10_000;
+
+/// Probes allowed per benchmark, including the two bracket checks.
+const MAX_PROBES: usize = 16;
+
+/// Smallest size searched.
+const BREAKEVEN_MIN: usize = 1024;
+
+/// Required advantage: parallel must be at least this much faster.
+const BREAKEVEN_SPEEDUP: f64 = 1.10;
+
+/// Bisects (geometrically) for the smallest n in `BREAKEVEN_MIN..=max_n` at
+/// which `measure(n)` reports par at least 10% faster than seq, assuming the
+/// speedup grows with n. Stops when the bracket is within 10% or the probe
+/// cap is reached, and prints the bracket found.
+fn find_breakeven(label: &str, max_n: usize, mut measure: impl FnMut(usize) -> (f64, f64)) {
+    let mut probes = 0;
+    let mut wins = |n: usize, probes: &mut usize| {
+        *probes += 1;
+        let (seq_ms, par_ms) = measure(n);
+        seq_ms / par_ms >= BREAKEVEN_SPEEDUP
+    };
+
+    if !wins(max_n, &mut probes) {
+        println!(
+            "  {:<15}no crossover up to {} ({} probes)",
+            label, max_n, probes
+        );
+        return;
+    }
+    if wins(BREAKEVEN_MIN, &mut probes) {
+        println!(
+            "  {:<15}wins already at {} ({} probes)",
+            label, BREAKEVEN_MIN, probes
+        );
+        return;
+    }
+    // Invariant: par loses at lo, wins at hi
+    let (mut lo, mut hi) = (BREAKEVEN_MIN, max_n);
+    while (hi as f64) > lo as f64 * 1.1 && probes < MAX_PROBES {
+        let mid = ((lo as f64 * hi as f64).sqrt() as usize).clamp(lo + 1, hi - 1);
+        if wins(mid, &mut probes) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    println!(
+        "  {:<15}crossover between {} and {} ({} probes)",
+        label, lo, hi, probes
+    );
+}
+
+/// Sequential and parallel map-and-collect times of `body` over `0..n`.
+fn breakeven_map(n: usize, body: impl Fn(i64) -> i64 + Sync + Send + Copy) -> (f64, f64) {
+    let input: Vec<i64> = (0..n as i64).collect();
+    let seq = time_adaptive_ms(|| {
+        black_box(input.iter().map(|&x| body(x)).collect::<Vec<i64>>());
+    });
+    let par = time_adaptive_ms(|| {
+        black_box(input.par_iter().map(|&x| body(x)).collect::<Vec<i64>>());
+    });
+    (seq, par)
+}
+
+/// `--find-breakeven`: the crossover size for sum, map and sort in each pool.
+fn run_breakeven() {
+    println!(
+        "=== Break-even search (par >= {:.0}% faster than seq) ===",
+        (BREAKEVEN_SPEEDUP - 1.0) * 100.0
+    );
+    on_each_pool(|| {
+        if !pools().is_empty() {
+            println!("  {} threads:", rayon::current_num_threads());
+        }
+        find_breakeven("Sum:", 1 << 24, |n| {
+            let data: Vec<i64> = (0..n).map(|i| (i % 1000) as i64).collect();
+            let seq = time_adaptive_ms(|| {
+                black_box(data.iter().sum::<i64>());
+            });
+            let par = time_adaptive_ms(|| {
+                black_box(data.par_iter().sum::<i64>());
+            });
+            (seq, par)
+        });
+        find_breakeven(
+            &work_label("Map", "x*2+1"),
+            1 << 24,
+            |n| match work_rounds() {
+                0 => breakeven_map(n, |x| x * 2 + 1),
+                rounds => breakeven_map(n, move |x| synthetic_work(x * 2 + 1, rounds)),
+            },
+        );
+        find_breakeven("Sort:", 1 << 22, |n| {
+            let mut rng = Rng::new(12345);
+            let original: Vec<i64> = (0..n).map(|_| rng.next() as i64).collect();
+            let seq = time_adaptive_with_setup_ms(|| original.clone(), |v| v.sort());
+            let par = time_adaptive_with_setup_ms(|| original.clone(), |v| v.par_sort());
+            (seq, par)
+        });
+    });
+}
+
 // ============================================================================
 // Random number generator (xorshift64 - matches Zig implementation)
 // ============================================================================
@@ -46,6 +720,8 @@ impl Rng {
 // ============================================================================
 
 fn main() {
+    init_global_pool();
+
     println!();
     println!("{}", "=".repeat(80));
     println!("                    Rust Rayon Benchmark Suite");
@@ -56,400 +732,5410 @@ fn main() {
         WARMUP_ITERATIONS,
         std::env::consts::ARCH
     );
-    println!(
-        "Rayon threads: {}",
-        rayon::current_num_threads()
-    );
+    if pools().is_empty() {
+        let pinned = if pinned_threads().is_some() {
+            " (pinned by --threads)"
+        } else {
+            ""
+        };
+        println!("Rayon threads: {}{}", rayon::current_num_threads(), pinned);
+    } else {
+        let sweep: Vec<String> = options().threads.iter().map(|t| t.to_string()).collect();
+        println!("Thread sweep: {} (dedicated pools)", sweep.join(", "));
+    }
+    if alloc_track::enabled() {
+        println!("Allocation tracking: enabled");
+    }
+    if options().work_ns > 0 {
+        println!(
+            "Per-element work: ~{}ns ({} synthetic_work rounds in map, for and filter)",
+            options().work_ns,
+            work_rounds()
+        );
+    }
     println!();
 
+    if options().find_breakeven {
+        run_breakeven();
+        println!();
+        return;
+    }
+
     bench_join_overhead();
     println!();
 
-    for &n in &SIZES {
-        println!("{}", "=".repeat(80));
-        println!("N = {:>12}", n);
-        println!("{}", "=".repeat(80));
-        println!();
+    bench_unbalanced_join();
+    println!();
 
-        bench_parallel_sum(n);
-        bench_parallel_map(n);
-        bench_parallel_reduce(n);
-        bench_parallel_for(n);
-        println!();
+    bench_join_depth();
+    println!();
+
+    println!("=== rayon::scope Spawn (~1us tasks) ===");
+    for tasks in SCOPE_TASKS {
+        bench_scope_spawn(tasks);
+    }
+    println!();
+
+    bench_spawn_fifo();
+    println!();
+
+    bench_divide_and_conquer();
+    println!();
+
+    bench_custom_split();
+    println!();
+
+    bench_memory_copy();
+    println!();
+
+    bench_chunked_sum();
+    println!();
+
+    bench_sum_element_types();
+    println!();
+
+    bench_aggregation_strategies();
+    println!();
+
+    bench_try_reduce();
+    println!();
+
+    bench_jagged_sum();
+    println!();
+
+    bench_box_blur();
+    println!();
+
+    println!("=== Matrix Multiplication ===");
+    for dim in MATMUL_DIMS {
+        bench_matmul(dim);
+    }
+    println!();
+
+    bench_transpose();
+    println!();
+
+    bench_reverse_rotate();
+    println!();
+
+    println!("=== SAXPY (f32) ===");
+    for n in SAXPY_SIZES {
+        bench_saxpy(n);
+    }
+    println!();
+
+    bench_zipped_slices();
+    println!();
+
+    bench_convolution();
+    println!();
+
+    bench_kahan_sum();
+    println!();
+
+    bench_simd_sum();
+    println!();
+
+    bench_mandelbrot();
+    println!();
+
+    bench_pool_configs();
+    println!();
+
+    bench_nested_install();
+    println!();
+
+    println!("=== Prime Sieve ===");
+    for (limit, known) in SIEVE_LIMITS {
+        bench_prime_sieve(limit, known);
+    }
+    println!();
+
+    println!("=== N-Body Step (all pairs, f64) ===");
+    for n in NBODY_SIZES {
+        bench_nbody(n);
+    }
+    println!();
+
+    bench_kmeans();
+    println!();
+
+    println!("=== Radix Sort (u64, vs sequential sort_unstable) ===");
+    for n in RADIX_SIZES {
+        bench_radix_sort(n);
+    }
+    println!();
+
+    println!("=== Dedup (50% duplicates) ===");
+    for n in DEDUP_SIZES {
+        bench_parallel_dedup(n);
+    }
+    println!();
+
+    bench_collect_hashmap();
+    println!();
+
+    bench_group_by();
+    println!();
+
+    bench_par_extend();
+    println!();
+
+    bench_string_building();
+    println!();
+
+    println!("=== Per-element Heap Allocation ===");
+    for n in ALLOC_SIZES {
+        bench_per_element_alloc(n);
+    }
+    println!();
+
+    bench_par_bridge();
+    println!();
+
+    bench_channel_pipeline();
+    println!();
+
+    bench_uneven_workload();
+    println!();
+
+    bench_oversubscription();
+    println!();
+
+    bench_dispatch_thresholds();
+    println!();
+
+    bench_checksum();
+    println!();
+
+    bench_word_count();
+    println!();
+
+    bench_csv_parse();
+    println!();
+
+    println!(
+        "=== Graph ({} nodes, avg degree {}) ===",
+        GRAPH_NODES, GRAPH_AVG_DEGREE
+    );
+    let graph = Graph::random(GRAPH_NODES, GRAPH_AVG_DEGREE, 54321);
+    bench_bfs(&graph);
+    bench_components(&graph, "CC(deg 16):");
+    drop(graph);
+    let sparse = Graph::random(GRAPH_NODES, SPARSE_AVG_DEGREE, 54321);
+    bench_components(&sparse, "CC(deg 2):");
+    println!();
+
+    for &n in &SIZES {
+        println!("{}", "=".repeat(80));
+        println!("N = {:>12}", n);
+        println!("{}", "=".repeat(80));
+        println!();
+
+        bench_parallel_sum(n);
+        bench_dot_product(n);
+        bench_parallel_map(n);
+        bench_parallel_reduce(n);
+        bench_parallel_for(n);
+        println!();
+
+        // Sort benchmarks (skip 10M due to memory)
+        if n <= 1_000_000 {
+            bench_parallel_sort(n);
+            bench_parallel_sort_by_key(n);
+            bench_parallel_sort_records(n);
+            bench_parallel_sort_strings(n);
+            println!();
+            bench_sort_patterns(n);
+            println!();
+        }
+
+        bench_parallel_merge(n);
+        bench_prefix_sum(n);
+        println!();
+
+        bench_parallel_median(n);
+        println!();
+
+        bench_parallel_histogram(n);
+        println!();
+
+        // Search benchmarks (~50ns per element; skip 10M)
+        if n <= 1_000_000 {
+            bench_parallel_find(n);
+            println!();
+            bench_parallel_any(n);
+            println!();
+            bench_parallel_position(n);
+            println!();
+        }
+
+        // Expensive-key sorts allocate per comparison; 1M takes seconds per pass
+        if n <= 100_000 {
+            bench_parallel_sort_cached_key(n);
+            println!();
+        }
+
+        // Iterator benchmarks
+        bench_parallel_iter_sum(n);
+        bench_range_vs_slice(n);
+        bench_parallel_iter_map_collect(n);
+        bench_parallel_iter_filter(n);
+        bench_parallel_partition(n);
+        bench_parallel_min_max_by_key(n);
+        bench_parallel_flat_map(n);
+        bench_parallel_to_uppercase(n);
+        bench_parallel_map_init(n);
+        println!();
+
+        // Split granularity
+        if n <= 1_000_000 {
+            bench_grain_size(n);
+            println!();
+        }
+    }
+
+    println!("{}", "=".repeat(80));
+    print_peak_bandwidth();
+    println!("Benchmark complete.");
+    println!("{}", "=".repeat(80));
+    println!();
+}
+
+// ============================================================================
+// Join Overhead
+// ============================================================================
+
+fn bench_join_overhead() {
+    println!("=== Join Overhead ===");
+
+    let iterations: usize = 100_000;
+
+    let results = on_each_pool(|| {
+        // Warmup
+        for _ in 0..1000 {
+            let (a, b) = rayon::join(|| 1i64, || 2i64);
+            black_box(a + b);
+        }
+
+        // Benchmark
+        let start = Instant::now();
+        let mut count: i64 = 0;
+        for _ in 0..iterations {
+            let (a, b) = rayon::join(|| 1i64, || 2i64);
+            count += a + b;
+        }
+        let elapsed_ns = start.elapsed().as_nanos() as f64;
+
+        let idle = latency_summary(join_latencies(iterations));
+        // A single worker runs the load only between joins, so it adds no contention
+        let loaded = (rayon::current_num_threads() > 1)
+            .then(|| latency_summary(with_background_load(|| join_latencies(iterations))));
+        (elapsed_ns / iterations as f64, count, idle, loaded)
+    });
+
+    for (threads, (avg_ns, count, idle, loaded)) in results {
+        if pinned_threads().is_some() {
+            println!(
+                "Empty join: {:.1}ns avg (total={})   [{}T]",
+                avg_ns, count, threads
+            );
+        } else if pools().is_empty() {
+            println!("Empty join: {:.1}ns avg (total={})", avg_ns, count);
+        } else {
+            println!(
+                "Empty join ({:>2}T): {:.1}ns avg (total={})",
+                threads, avg_ns, count
+            );
+        }
+        println!("  {:<8}{}", "idle:", idle);
+        match loaded {
+            Some(loaded) => println!("  {:<8}{}", "loaded:", loaded),
+            None => println!("  {:<8}skipped (1 thread)", "loaded:"),
+        }
+    }
+}
+
+/// Times `iterations` empty joins one by one, in ns. The buffer is
+/// allocated up front so recording does not allocate; each sample includes
+/// one clock read pair.
+fn join_latencies(iterations: usize) -> Vec<u64> {
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let (a, b) = rayon::join(|| 1i64, || 2i64);
+        samples.push(start.elapsed().as_nanos() as u64);
+        black_box(a + b);
+    }
+    samples
+}
+
+/// Mean and tail percentiles of latency samples in ns, e.g.
+/// `mean 52ns, p50 40, p90 61, p99 210, p999 4100, max 18000`.
+fn latency_summary(mut samples: Vec<u64>) -> String {
+    samples.sort_unstable();
+    let pct = |q: f64| samples[((q * samples.len() as f64) as usize).min(samples.len() - 1)];
+    let mean = samples.iter().sum::<u64>() as f64 / samples.len() as f64;
+    format!(
+        "mean {:.0}ns, p50 {}, p90 {}, p99 {}, p999 {}, max {}",
+        mean,
+        pct(0.50),
+        pct(0.90),
+        pct(0.99),
+        pct(0.999),
+        samples[samples.len() - 1]
+    )
+}
+
+/// Elements summed per pass of the background load (~30µs on one thread).
+const BACKGROUND_LOAD_LEN: usize = 100_000;
+
+/// Runs `f` while a `rayon::spawn`ed task keeps the current pool busy with
+/// parallel sums, yielding to other pool work between passes. The load
+/// starts before `f` and is waited for after it; waiting goes through
+/// `rayon::yield_now` so a worker thread can run the load itself instead of
+/// blocking on it.
+fn with_background_load<R>(f: impl FnOnce() -> R) -> R {
+    let stop = Arc::new(AtomicBool::new(false));
+    let done = Arc::new(AtomicBool::new(false));
+    let (load_stop, load_done) = (stop.clone(), done.clone());
+    rayon::spawn(move || {
+        let data: Vec<u64> = (0..BACKGROUND_LOAD_LEN as u64).collect();
+        while !load_stop.load(Ordering::Relaxed) {
+            black_box(data.par_iter().sum::<u64>());
+            // A worker never leaves this task on its own; without yielding,
+            // joins injected from outside the pool would wait for `stop`
+            rayon::yield_now();
+        }
+        load_done.store(true, Ordering::Release);
+    });
+
+    let result = f();
+    stop.store(true, Ordering::Relaxed);
+    while !done.load(Ordering::Acquire) {
+        if rayon::yield_now().is_none() {
+            std::thread::yield_now();
+        }
+    }
+    result
+}
+
+// ============================================================================
+// Unbalanced Join
+// ============================================================================
+
+/// Lopsided joins per timed run.
+const UNBALANCED_JOINS: usize = 50;
+
+/// `synthetic_work` rounds for roughly `us` microseconds of work.
+fn rounds_for_us(us: usize) -> usize {
+    us * expensive_rounds()
+}
+
+/// `rayon::join` of a 10µs branch with a 1ms branch, against running both
+/// branches back to back. The short branch's thread can only help if the
+/// long branch exposes stealable work, so the 1ms branch is also run split
+/// into 100 x 10µs pieces via `par_iter`. A join of two 505µs branches is
+/// the balanced control.
+fn bench_unbalanced_join() {
+    println!(
+        "=== Unbalanced Join (10us + 1ms branches, {} joins) ===",
+        UNBALANCED_JOINS
+    );
+
+    let short = rounds_for_us(10);
+    let long = rounds_for_us(1000);
+    let half = rounds_for_us(505);
+    let long_split = || -> i64 {
+        (0..100i64)
+            .into_par_iter()
+            .map(|i| synthetic_work(i, short))
+            .reduce(|| 0, |a, b| a.wrapping_add(b))
+    };
+
+    let seq_ms = time_ms(|| {
+        for i in 0..UNBALANCED_JOINS as i64 {
+            black_box(synthetic_work(i, short));
+            black_box(synthetic_work(i, long));
+        }
+    });
+    let lopsided = time_par_ms(|| {
+        for i in 0..UNBALANCED_JOINS as i64 {
+            black_box(rayon::join(
+                || synthetic_work(i, short),
+                || synthetic_work(i, long),
+            ));
+        }
+    });
+    let split = time_par_ms(|| {
+        for i in 0..UNBALANCED_JOINS as i64 {
+            black_box(rayon::join(|| synthetic_work(i, short), long_split));
+        }
+    });
+    println!("{}", speedup_line("lopsided:", seq_ms, &lopsided));
+    println!("{}", speedup_line("long split:", seq_ms, &split));
+
+    let balanced_seq_ms = time_ms(|| {
+        for i in 0..UNBALANCED_JOINS as i64 {
+            black_box(synthetic_work(i, half));
+            black_box(synthetic_work(!i, half));
+        }
+    });
+    let balanced = time_par_ms(|| {
+        for i in 0..UNBALANCED_JOINS as i64 {
+            black_box(rayon::join(
+                || synthetic_work(i, half),
+                || synthetic_work(!i, half),
+            ));
+        }
+    });
+    println!("{}", speedup_line("balanced:", balanced_seq_ms, &balanced));
+}
+
+// ============================================================================
+// Recursive Join Depth
+// ============================================================================
+
+/// Elements summed by the recursive-join benchmark.
+const JOIN_SUM_LEN: usize = 1_000_000;
+
+/// Sums `data` by halving with `rayon::join` until a slice fits in `leaf`.
+fn join_sum(data: &[i64], leaf: usize) -> i64 {
+    if data.len() <= leaf {
+        return data.iter().sum();
+    }
+    let (left, right) = data.split_at(data.len() / 2);
+    let (a, b) = rayon::join(|| join_sum(left, leaf), || join_sum(right, leaf));
+    a + b
+}
+
+/// Number of `rayon::join` calls `join_sum` makes for `len` elements.
+fn join_count(len: usize, leaf: usize) -> usize {
+    if len <= leaf {
+        return 0;
+    }
+    1 + join_count(len / 2, leaf) + join_count(len - len / 2, leaf)
+}
+
+/// Sum of 1M elements through raw recursive `rayon::join` at several leaf
+/// sizes, with `par_iter().sum()` for reference. Leaf 1 costs ~n joins, so
+/// the spread between rows is per-join overhead at that granularity.
+fn bench_join_depth() {
+    println!("=== Recursive Join Sum (1M i64) ===");
+
+    let data: Vec<i64> = (0..JOIN_SUM_LEN).map(|i| (i % 1000) as i64).collect();
+    let expected: i64 = data.iter().sum();
+
+    let seq_ms = time_ms(|| {
+        black_box(data.iter().sum::<i64>());
+    });
+
+    for leaf in [1, 64, 4096, 256 * 1024] {
+        let par = time_par_ms(|| {
+            black_box(join_sum(&data, leaf));
+        });
+        let label = format!("leaf {}:", leaf);
+        println!(
+            "{} (correct={}, {} joins)",
+            speedup_line(&label, seq_ms, &par),
+            join_sum(&data, leaf) == expected,
+            join_count(JOIN_SUM_LEN, leaf)
+        );
+    }
+
+    let par = time_par_ms(|| {
+        black_box(data.par_iter().sum::<i64>());
+    });
+    println!(
+        "{} (correct={})",
+        speedup_line("par_iter sum:", seq_ms, &par),
+        data.par_iter().sum::<i64>() == expected
+    );
+}
+
+// ============================================================================
+// Scope Spawn Fan-out
+// ============================================================================
+
+const SCOPE_TASKS: [usize; 3] = [100, 10_000, 1_000_000];
+
+/// `tasks` ~1µs tasks as individual `rayon::scope` spawns, as a parent spawn
+/// that itself spawns two children, and as `into_par_iter().for_each`, which
+/// can batch. Each task adds its result to a shared atomic. The ns/elem
+/// columns are per task; the gap to `seq` is the per-spawn overhead.
+fn bench_scope_spawn(tasks: usize) {
+    let rounds = expensive_rounds();
+    let work = move |i: usize| synthetic_work(i as i64, rounds) as u64;
+    let expected = (0..tasks).fold(0u64, |acc, i| acc.wrapping_add(work(i)));
+
+    let seq_ms = time_ms(|| {
+        black_box((0..tasks).fold(0u64, |acc, i| acc.wrapping_add(work(i))));
+    });
+
+    let spawn_flat = || -> u64 {
+        let total = AtomicU64::new(0);
+        rayon::scope(|s| {
+            for i in 0..tasks {
+                let total = &total;
+                s.spawn(move |_| {
+                    total.fetch_add(work(i), Ordering::Relaxed);
+                });
+            }
+        });
+        total.into_inner()
+    };
+    // Task 3p spawns tasks 3p + 1 and 3p + 2
+    let spawn_nested = || -> u64 {
+        let total = AtomicU64::new(0);
+        rayon::scope(|s| {
+            for parent in (0..tasks).step_by(3) {
+                let total = &total;
+                s.spawn(move |s| {
+                    total.fetch_add(work(parent), Ordering::Relaxed);
+                    for child in (parent + 1..parent + 3).filter(|&c| c < tasks) {
+                        s.spawn(move |_| {
+                            total.fetch_add(work(child), Ordering::Relaxed);
+                        });
+                    }
+                });
+            }
+        });
+        total.into_inner()
+    };
+    let for_each = || -> u64 {
+        let total = AtomicU64::new(0);
+        (0..tasks).into_par_iter().for_each(|i| {
+            total.fetch_add(work(i), Ordering::Relaxed);
+        });
+        total.into_inner()
+    };
+
+    println!("  tasks = {}:", tasks);
+    let variants: [(&str, &(dyn Fn() -> u64 + Sync)); 3] = [
+        ("scope spawn:", &spawn_flat),
+        ("nested spawn:", &spawn_nested),
+        ("for_each:", &for_each),
+    ];
+    for (label, run) in variants {
+        let par = time_par_ms(|| {
+            black_box(run());
+        });
+        println!(
+            "{} (correct={}) {}",
+            speedup_line(label, seq_ms, &par),
+            run() == expected,
+            per_elem_note(tasks, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// spawn vs spawn_fifo
+// ============================================================================
+
+/// Tasks submitted per scope in the spawn-order benchmark.
+const FIFO_TASKS: usize = 100_000;
+
+/// `synthetic_work` rounds per task (~100ns).
+const FIFO_TASK_ROUNDS: usize = 50;
+
+/// Spawns `FIFO_TASKS` tasks in submission order, each recording its
+/// completion rank into `slots[index]`.
+fn spawn_tagged(fifo: bool, slots: &[AtomicUsize]) {
+    let ticket = AtomicUsize::new(0);
+    let task = |i: usize| {
+        black_box(synthetic_work(i as i64, FIFO_TASK_ROUNDS));
+        slots[i].store(ticket.fetch_add(1, Ordering::Relaxed), Ordering::Relaxed);
+    };
+    let task = &task;
+    if fifo {
+        rayon::scope_fifo(|s| {
+            for i in 0..FIFO_TASKS {
+                s.spawn_fifo(move |_| task(i));
+            }
+        });
+    } else {
+        rayon::scope(|s| {
+            for i in 0..FIFO_TASKS {
+                s.spawn(move |_| task(i));
+            }
+        });
+    }
+}
+
+/// Mean, p99 and max of |completion rank - submission index|.
+fn order_skew(slots: &[AtomicUsize]) -> (f64, usize, usize) {
+    let mut skew: Vec<usize> = slots
+        .iter()
+        .enumerate()
+        .map(|(i, rank)| rank.load(Ordering::Relaxed).abs_diff(i))
+        .collect();
+    skew.sort_unstable();
+    let mean = skew.iter().sum::<usize>() as f64 / skew.len() as f64;
+    (mean, skew[skew.len() * 99 / 100], skew[skew.len() - 1])
+}
+
+/// 100K ~100ns tasks through `scope`/`spawn` (LIFO on the spawning worker's
+/// deque) and `scope_fifo`/`spawn_fifo`: throughput against a sequential
+/// loop, and how far each task's completion rank lands from its submission
+/// index.
+fn bench_spawn_fifo() {
+    println!("=== spawn vs spawn_fifo (100K ~100ns tasks) ===");
+
+    let seq_ms = time_ms(|| {
+        for i in 0..FIFO_TASKS {
+            black_box(synthetic_work(i as i64, FIFO_TASK_ROUNDS));
+        }
+    });
+
+    let slots: Vec<AtomicUsize> = (0..FIFO_TASKS).map(|_| AtomicUsize::new(0)).collect();
+    for (label, fifo) in [("spawn:", false), ("spawn_fifo:", true)] {
+        let par = time_par_ms(|| spawn_tagged(fifo, &slots));
+        let skews = on_each_pool(|| {
+            spawn_tagged(fifo, &slots);
+            order_skew(&slots)
+        });
+        let notes: Vec<String> = skews
+            .iter()
+            .map(|&(threads, (mean, p99, max))| {
+                let tag = if pools().is_empty() {
+                    String::new()
+                } else {
+                    format!("{}T ", threads)
+                };
+                format!("{}mean {:.0}, p99 {}, max {}", tag, mean, p99, max)
+            })
+            .collect();
+        println!(
+            "{} (skew: {})",
+            speedup_line(label, seq_ms, &par),
+            notes.join("; ")
+        );
+    }
+}
+
+// ============================================================================
+// Recursive Divide and Conquer (quicksort / fib)
+// ============================================================================
+
+/// Elements sorted by the hand-written quicksort.
+const QSORT_LEN: usize = 1_000_000;
+
+const QSORT_CUTOFFS: [usize; 3] = [1024, 8 * 1024, 64 * 1024];
+
+const FIB_N: u32 = 35;
+
+/// Below this `n` the parallel fib recurses sequentially.
+const FIB_CUTOFFS: [u32; 3] = [10, 20, 30];
+
+/// Lomuto partition around the median of first, middle and last; returns
+/// the pivot's final index.
+fn partition(v: &mut [i64]) -> usize {
+    let (last, mid) = (v.len() - 1, v.len() / 2);
+    if v[mid] < v[0] {
+        v.swap(mid, 0);
+    }
+    if v[last] < v[0] {
+        v.swap(last, 0);
+    }
+    if v[mid] < v[last] {
+        v.swap(mid, last);
+    }
+    let pivot = v[last];
+    let mut store = 0;
+    for i in 0..last {
+        if v[i] < pivot {
+            v.swap(i, store);
+            store += 1;
+        }
+    }
+    v.swap(store, last);
+    store
+}
+
+/// Quicksort that recurses through `rayon::join` and hands slices of at
+/// most `cutoff` elements to `sort_unstable`.
+fn quicksort_par(v: &mut [i64], cutoff: usize) {
+    if v.len() <= cutoff.max(1) {
+        v.sort_unstable();
+        return;
+    }
+    let p = partition(v);
+    let (lo, hi) = v.split_at_mut(p);
+    rayon::join(
+        || quicksort_par(lo, cutoff),
+        || quicksort_par(&mut hi[1..], cutoff),
+    );
+}
+
+fn fib_seq(n: u32) -> u64 {
+    if n < 2 {
+        return n as u64;
+    }
+    fib_seq(n - 1) + fib_seq(n - 2)
+}
+
+/// Exponential fib recursion through `rayon::join` down to `cutoff`.
+fn fib_par(n: u32, cutoff: u32) -> u64 {
+    if n <= cutoff {
+        return fib_seq(n);
+    }
+    let (a, b) = rayon::join(|| fib_par(n - 1, cutoff), || fib_par(n - 2, cutoff));
+    a + b
+}
+
+/// Two recursion shapes built directly on `rayon::join`, each swept over
+/// its sequential cutoff: a 1M-element quicksort (against sequential
+/// `sort_unstable`, with `par_sort_unstable` for reference) and fib(35).
+fn bench_divide_and_conquer() {
+    println!("=== Recursive Divide and Conquer ===");
+
+    let mut rng = Rng::new(54321);
+    let original: Vec<i64> = (0..QSORT_LEN).map(|_| rng.next() as i64).collect();
+    let mut expected = original.clone();
+    expected.sort_unstable();
+
+    println!("  Quicksort (1M i64):");
+    let seq_ms = time_with_setup_ms(|| original.clone(), |v| v.sort_unstable());
+    for cutoff in QSORT_CUTOFFS {
+        let par = time_par_with_setup_ms(|| original.clone(), |v| quicksort_par(v, cutoff));
+        let mut sorted = original.clone();
+        quicksort_par(&mut sorted, cutoff);
+        let label = format!("cutoff {}:", cutoff);
+        println!(
+            "{} (correct={})",
+            speedup_line(&label, seq_ms, &par),
+            sorted == expected
+        );
+    }
+    let par = time_par_with_setup_ms(|| original.clone(), |v| v.par_sort_unstable());
+    println!("{}", speedup_line("par_sort_unst:", seq_ms, &par));
+
+    println!("  fib({}):", FIB_N);
+    let expected = fib_seq(FIB_N);
+    let seq_ms = time_ms(|| {
+        black_box(fib_seq(black_box(FIB_N)));
+    });
+    for cutoff in FIB_CUTOFFS {
+        let par = time_par_ms(|| {
+            black_box(fib_par(black_box(FIB_N), cutoff));
+        });
+        let label = format!("cutoff {}:", cutoff);
+        println!(
+            "{} (correct={})",
+            speedup_line(&label, seq_ms, &par),
+            fib_par(FIB_N, cutoff) == expected
+        );
+    }
+}
+
+// ============================================================================
+// Custom Splitter (rayon::iter::split)
+// ============================================================================
+
+/// Elements covered by the implicit tree in the custom-splitter benchmark.
+const SPLIT_LEN: usize = 10_000_000;
+
+/// Ranges at or below this length are not split further.
+const SPLIT_LEAF: usize = 4096;
+
+/// Sums `data` by walking an implicit binary tree of index ranges with
+/// `rayon::iter::split`: node `lo..hi` has children `lo..mid` and `mid..hi`,
+/// where `mid` sits `left_share` of the way along the range. rayon decides
+/// how deep to split; each leaf range is summed sequentially.
+fn split_sum(data: &[i64], left_share: f64) -> i64 {
+    rayon::iter::split(0..data.len(), |range| {
+        let len = range.len();
+        if len <= SPLIT_LEAF {
+            return (range, None);
+        }
+        let mid = range.start + ((len as f64 * left_share) as usize).clamp(1, len - 1);
+        (range.start..mid, Some(mid..range.end))
+    })
+    .map(|range| data[range].iter().sum::<i64>())
+    .sum()
+}
+
+/// `rayon::iter::split` over an implicit range tree with even and 90/10
+/// splits, against `par_iter().sum()` on the flat slice. Lopsided splits
+/// make deep, skinny trees that only stay balanced because idle workers
+/// steal the large halves.
+fn bench_custom_split() {
+    println!("=== Custom Splitter (rayon::iter::split, 10M i64) ===");
+
+    let data: Vec<i64> = (0..SPLIT_LEN).map(|i| (i % 1000) as i64).collect();
+    let expected: i64 = data.iter().sum();
+
+    let seq_ms = time_ms(|| {
+        black_box(data.iter().sum::<i64>());
+    });
+
+    for (label, share) in [("split 50/50:", 0.5), ("split 90/10:", 0.9)] {
+        let par = time_par_ms(|| {
+            black_box(split_sum(&data, share));
+        });
+        println!(
+            "{} (correct={})",
+            speedup_line(label, seq_ms, &par),
+            split_sum(&data, share) == expected
+        );
+    }
+
+    let par = time_par_ms(|| {
+        black_box(data.par_iter().sum::<i64>());
+    });
+    println!("{}", speedup_line("par_iter sum:", seq_ms, &par));
+}
+
+// ============================================================================
+// Memory Copy
+// ============================================================================
+
+const COPY_BYTES: usize = 1024 * 1024 * 1024;
+
+/// Every copied byte is read once and written once.
+const COPY_TRAFFIC: Bandwidth = Bandwidth {
+    label: "Copy",
+    bytes_per_elem: 2,
+};
+
+/// Copies a 1GB buffer with one `copy_from_slice`, chunk by chunk, and as
+/// parallel chunks. Copy does no compute, so its GB/s is the ceiling the
+/// other bandwidth-bound results can be read against.
+fn bench_memory_copy() {
+    println!("=== Memory Copy (1GB) ===");
+
+    let mut rng = Rng::new(54321);
+    let mut src = vec![0u8; COPY_BYTES];
+    for word in src.chunks_exact_mut(8) {
+        word.copy_from_slice(&rng.next().to_le_bytes());
+    }
+    let mut dst = vec![0u8; COPY_BYTES];
+    let sampled_ok = |dst: &[u8]| {
+        let mut rng = Rng::new(12345);
+        (0..1000).all(|_| {
+            let i = rng.next() as usize % COPY_BYTES;
+            dst[i] == src[i]
+        })
+    };
+
+    let seq_ms = time_ms(|| {
+        dst.copy_from_slice(&src);
+        black_box(&dst);
+    });
+    println!(
+        "  {:<15}seq {:>8.3}ms (correct={}) {}",
+        "whole copy:",
+        seq_ms,
+        sampled_ok(&dst),
+        COPY_TRAFFIC.note(COPY_BYTES, seq_ms, &[])
+    );
+
+    for chunk in [1024 * 1024, 16 * 1024 * 1024] {
+        // Clear between variants so each check sees only its own writes
+        dst.fill(0);
+        let seq_ms = time_ms(|| {
+            for (d, s) in dst.chunks_mut(chunk).zip(src.chunks(chunk)) {
+                d.copy_from_slice(s);
+            }
+            black_box(&dst);
+        });
+        let seq_ok = sampled_ok(&dst);
+
+        dst.fill(0);
+        let par = time_par_ms(|| {
+            dst.par_chunks_mut(chunk)
+                .zip(src.par_chunks(chunk))
+                .for_each(|(d, s)| d.copy_from_slice(s));
+            black_box(&dst);
+        });
+        println!(
+            "{} (correct={}) {}",
+            speedup_line(&format!("{}MB chunks:", chunk >> 20), seq_ms, &par),
+            seq_ok && sampled_ok(&dst),
+            COPY_TRAFFIC.note(COPY_BYTES, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// Chunked Processing (par_chunks / par_chunks_exact)
+// ============================================================================
+
+/// Elements in the fixed-size chunked benchmark (80 MB of u64).
+const CHUNKED_LEN: usize = 10_000_000;
+
+/// Each chunked-sum element is read once.
+const CHUNKED_TRAFFIC: Bandwidth = Bandwidth {
+    label: "Chunked sum",
+    bytes_per_elem: std::mem::size_of::<u64>(),
+};
+
+/// Per-chunk sums over a 10M-element slice. None of the chunk sizes divide
+/// 10M, so `par_chunks_exact` has to add the remainder's sum by hand.
+fn bench_chunked_sum() {
+    println!("=== Chunked Sum (10M u64) ===");
+
+    let data: Vec<u64> = (0..CHUNKED_LEN as u64).map(|i| i % 1000).collect();
+
+    for chunk in [1024, 16 * 1024, 256 * 1024] {
+        println!("  chunk = {}:", chunk);
+
+        let expected: Vec<u64> = data.chunks(chunk).map(|c| c.iter().sum()).collect();
+        let seq_ms = time_ms(|| {
+            let sums: Vec<u64> = data.chunks(chunk).map(|c| c.iter().sum()).collect();
+            black_box(sums);
+        });
+
+        let chunks = || -> Vec<u64> { data.par_chunks(chunk).map(|c| c.iter().sum()).collect() };
+        let par = time_par_ms(|| {
+            black_box(chunks());
+        });
+        println!(
+            "{} (correct={}) {}",
+            speedup_line("par_chunks:", seq_ms, &par),
+            chunks() == expected,
+            CHUNKED_TRAFFIC.note(CHUNKED_LEN, seq_ms, &par)
+        );
+
+        let chunks_exact = || -> Vec<u64> {
+            let exact = data.par_chunks_exact(chunk);
+            let rest = exact.remainder();
+            let mut sums: Vec<u64> = exact.map(|c| c.iter().sum()).collect();
+            if !rest.is_empty() {
+                sums.push(rest.iter().sum());
+            }
+            sums
+        };
+        let par = time_par_ms(|| {
+            black_box(chunks_exact());
+        });
+        println!(
+            "{} (correct={}) {}",
+            speedup_line("chunks_exact:", seq_ms, &par),
+            chunks_exact() == expected,
+            CHUNKED_TRAFFIC.note(CHUNKED_LEN, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// Aggregation Strategies (sum / reduce / fold / fold_chunks)
+// ============================================================================
+
+/// Elements in the aggregation-strategy benchmark.
+const AGGREGATE_LEN: usize = 10_000_000;
+
+/// Chunk length handed to `fold_chunks`.
+const FOLD_CHUNK: usize = 4096;
+
+/// Weight of element `i` in the weighted sum (1..=8).
+#[inline]
+fn weight(i: usize) -> i64 {
+    (i % 8) as i64 + 1
+}
+
+/// The same weighted sum through each of rayon's aggregation entry points.
+/// The per-element work is identical, so any gap between the variants is
+/// splitting and combining overhead.
+fn bench_aggregation_strategies() {
+    println!("=== Aggregation Strategies (10M weighted i64 sum) ===");
+
+    let data: Vec<i64> = (0..AGGREGATE_LEN).map(|i| (i % 1000) as i64).collect();
+    let weighted = |(i, &x): (usize, &i64)| x * weight(i);
+    let expected: i64 = data.iter().enumerate().map(weighted).sum();
+
+    let seq_ms = time_ms(|| {
+        black_box(data.iter().enumerate().map(weighted).sum::<i64>());
+    });
+
+    let sum = || -> i64 { data.par_iter().enumerate().map(weighted).sum() };
+    let reduce = || -> i64 {
+        data.par_iter()
+            .enumerate()
+            .map(weighted)
+            .reduce(|| 0, |a, b| a + b)
+    };
+    let fold = || -> i64 {
+        data.par_iter()
+            .enumerate()
+            .fold(|| 0i64, |acc, item| acc + weighted(item))
+            .reduce(|| 0, |a, b| a + b)
+    };
+    let fold_chunks = || -> i64 {
+        data.par_iter()
+            .enumerate()
+            .fold_chunks(FOLD_CHUNK, || 0i64, |acc, item| acc + weighted(item))
+            .sum()
+    };
+
+    let variants: [(&str, &(dyn Fn() -> i64 + Sync)); 4] = [
+        ("sum:", &sum),
+        ("reduce:", &reduce),
+        ("fold+reduce:", &fold),
+        ("fold_chunks:", &fold_chunks),
+    ];
+    for (label, run) in variants {
+        let par = time_par_ms(|| {
+            black_box(run());
+        });
+        println!(
+            "{} (correct={}) {}",
+            speedup_line(label, seq_ms, &par),
+            run() == expected,
+            per_elem_note(AGGREGATE_LEN, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// Fallible Reduction (try_fold / try_reduce early exit)
+// ============================================================================
+
+/// Elements in the fallible-reduction benchmark.
+const TRY_LEN: usize = 10_000_000;
+
+/// A running sum, or the index of the poison element that stopped it.
+type TrySum = Result<i64, usize>;
+
+/// Adds `x` unless it is a poison value. Poison at index `i` is stored as
+/// `-(i + 1)`, so the error reports where the bad element sits.
+#[inline]
+fn checked_step(acc: i64, x: i64) -> TrySum {
+    if x < 0 {
+        Err((-x - 1) as usize)
+    } else {
+        Ok(acc + x)
+    }
+}
+
+/// The parallel strategies compared against a sequential `try_fold`.
+#[derive(Clone, Copy)]
+enum TryStrategy {
+    /// `try_fold` per split, then `try_reduce` across splits.
+    Fold,
+    /// Per-element `Result` fed straight into `try_reduce`.
+    Reduce,
+    /// Map everything to `Result`, collect, and only then look for an error.
+    Collect,
+}
+
+impl TryStrategy {
+    const ALL: [(&'static str, TryStrategy); 3] = [
+        ("try_fold:", TryStrategy::Fold),
+        ("try_reduce:", TryStrategy::Reduce),
+        ("collect+check:", TryStrategy::Collect),
+    ];
+
+    /// Runs the strategy over `data`. `visit` is called once per element
+    /// looked at; pass `|| {}` when timing.
+    fn run(self, data: &[i64], visit: impl Fn() + Sync) -> TrySum {
+        let step = |acc: i64, x: i64| {
+            visit();
+            checked_step(acc, x)
+        };
+        match self {
+            TryStrategy::Fold => data
+                .par_iter()
+                .try_fold(|| 0i64, |acc, &x| step(acc, x))
+                .try_reduce(|| 0, |a, b| Ok(a + b)),
+            TryStrategy::Reduce => data
+                .par_iter()
+                .map(|&x| step(0, x))
+                .try_reduce(|| 0, |a, b| Ok(a + b)),
+            TryStrategy::Collect => {
+                let results: Vec<TrySum> = data.par_iter().map(|&x| step(0, x)).collect();
+                results.into_iter().try_fold(0, |acc, r| r.map(|x| acc + x))
+            }
+        }
+    }
+}
+
+/// Fallible sums with one poison element at 1%, at 50%, or absent. Besides
+/// timing, an instrumented run counts how many elements each strategy looks
+/// at before it returns, i.e. how much work continues after the error exists.
+fn bench_try_reduce() {
+    println!("=== Fallible Sum (try_fold / try_reduce, 10M i64) ===");
+
+    let clean: Vec<i64> = (0..TRY_LEN).map(|i| (i % 1000) as i64).collect();
+
+    let placements = [
+        ("1%", Some(TRY_LEN / 100)),
+        ("50%", Some(TRY_LEN / 2)),
+        ("none", None),
+    ];
+    for (name, poison) in placements {
+        println!("  poison at {}:", name);
+        let mut data = clean.clone();
+        if let Some(i) = poison {
+            data[i] = -(i as i64) - 1;
+        }
+
+        let expected = data.iter().try_fold(0i64, |acc, &x| checked_step(acc, x));
+        let seq_ms = time_ms(|| {
+            black_box(data.iter().try_fold(0i64, |acc, &x| checked_step(acc, x))).ok();
+        });
+
+        for (label, strategy) in TryStrategy::ALL {
+            let par = time_par_ms(|| {
+                black_box(strategy.run(&data, || {})).ok();
+            });
+            let seen = AtomicU64::new(0);
+            let result = strategy.run(&data, || {
+                seen.fetch_add(1, Ordering::Relaxed);
+            });
+            let visited = seen.load(Ordering::Relaxed) as f64 / TRY_LEN as f64 * 100.0;
+            println!(
+                "{} (correct={}, visited {:.1}%)",
+                speedup_line(label, seq_ms, &par),
+                result == expected,
+                visited
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Jagged Sum (nested Vec<Vec<i64>>)
+// ============================================================================
+
+/// Inner vectors in the jagged-sum benchmark.
+const JAGGED_ROWS: usize = 10_000;
+
+/// Mean inner length; lengths are exponentially distributed around it.
+const JAGGED_MEAN_LEN: f64 = 1000.0;
+
+/// Sums 10K inner vectors of exponentially distributed length (~1K mean)
+/// three ways: nested loops, `par_iter` over the rows with sequential row
+/// sums, and `par_iter` over the rows with a `par_iter` sum inside each.
+/// The inner level adds splits for ~1K elements of work, so the fully nested
+/// version is expected to lose to outer-only.
+fn bench_jagged_sum() {
+    println!("=== Jagged Sum (10K rows, ~1K avg) ===");
+
+    let mut rng = Rng::new(54321);
+    let rows: Vec<Vec<i64>> = (0..JAGGED_ROWS)
+        .map(|_| {
+            let u = (rng.next() >> 11) as f64 / (1u64 << 53) as f64;
+            let len = (-(1.0 - u).ln() * JAGGED_MEAN_LEN) as usize;
+            (0..len).map(|_| (rng.next() % 1000) as i64).collect()
+        })
+        .collect();
+    let elements: usize = rows.iter().map(Vec::len).sum();
+
+    let nested_loops = || -> i64 { rows.iter().map(|r| r.iter().sum::<i64>()).sum() };
+    let expected = nested_loops();
+    let seq_ms = time_ms(|| {
+        black_box(nested_loops());
+    });
+
+    let outer_only = || -> i64 { rows.par_iter().map(|r| r.iter().sum::<i64>()).sum() };
+    let fully_nested = || -> i64 { rows.par_iter().map(|r| r.par_iter().sum::<i64>()).sum() };
+
+    let variants: [(&str, &(dyn Fn() -> i64 + Sync)); 2] =
+        [("outer only:", &outer_only), ("nested:", &fully_nested)];
+    for (label, run) in variants {
+        let par = time_par_ms(|| {
+            black_box(run());
+        });
+        println!(
+            "{} (correct={}) {}",
+            speedup_line(label, seq_ms, &par),
+            run() == expected,
+            per_elem_note(elements, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// 2D Stencil (3x3 box blur)
+// ============================================================================
+
+const IMAGE_WIDTH: usize = 4096;
+const IMAGE_HEIGHT: usize = 4096;
+
+/// Blurs row `y` of `src` into `out` with a 3x3 box filter, clamping
+/// neighbour coordinates at the image edges.
+fn blur_row(src: &[u8], width: usize, height: usize, y: usize, out: &mut [u8]) {
+    let rows = [y.saturating_sub(1), y, (y + 1).min(height - 1)];
+    for (x, px) in out.iter_mut().enumerate() {
+        let cols = [x.saturating_sub(1), x, (x + 1).min(width - 1)];
+        let mut sum = 0u32;
+        for &r in &rows {
+            for &c in &cols {
+                sum += src[r * width + c] as u32;
+            }
+        }
+        *px = (sum / 9) as u8;
+    }
+}
+
+fn bench_box_blur() {
+    println!("=== Box Blur 3x3 ({}x{} u8) ===", IMAGE_WIDTH, IMAGE_HEIGHT);
+
+    let (w, h) = (IMAGE_WIDTH, IMAGE_HEIGHT);
+    let mut rng = Rng::new(54321);
+    let src: Vec<u8> = (0..w * h).map(|_| rng.next() as u8).collect();
+    let megapixels = (w * h) as f64 / 1e6;
+
+    let blur_seq = |out: &mut Vec<u8>| {
+        for (y, row) in out.chunks_mut(w).enumerate() {
+            blur_row(&src, w, h, y, row);
+        }
+    };
+    let blur_par = |out: &mut Vec<u8>| {
+        out.par_chunks_mut(w)
+            .enumerate()
+            .for_each(|(y, row)| blur_row(&src, w, h, y, row));
+    };
+
+    let seq_ms = time_with_setup_ms(|| vec![0u8; w * h], blur_seq);
+    let par = time_par_with_setup_ms(|| vec![0u8; w * h], blur_par);
+
+    // Verify correctness: the parallel output must be byte-identical
+    let (mut expected, mut actual) = (vec![0u8; w * h], vec![0u8; w * h]);
+    blur_seq(&mut expected);
+    blur_par(&mut actual);
+    assert!(expected == actual, "parallel blur differs from sequential");
+
+    println!(
+        "{} {}",
+        speedup_line("Blur(rows):", seq_ms, &par),
+        rate_note("MP/s", megapixels, seq_ms, &par)
+    );
+}
+
+// ============================================================================
+// Matrix Multiplication
+// ============================================================================
+
+const MATMUL_DIMS: [usize; 3] = [256, 512, 1024];
+
+/// Tile edge of the blocked sequential matmul (three 64x64 f64 tiles = 96KB).
+const MATMUL_TILE: usize = 64;
+
+/// One output row of `a * b` in i-k-j order: `row += a[i][k] * b[k][..]`.
+fn matmul_row(a_row: &[f64], b: &[f64], dim: usize, row: &mut [f64]) {
+    row.fill(0.0);
+    for (k, &a_ik) in a_row.iter().enumerate() {
+        let b_row = &b[k * dim..(k + 1) * dim];
+        for (r, &b_kj) in row.iter_mut().zip(b_row) {
+            *r += a_ik * b_kj;
+        }
+    }
+}
+
+/// i-k-j matmul over `MATMUL_TILE` tiles so each tile of `b` stays in cache.
+fn matmul_blocked(a: &[f64], b: &[f64], dim: usize, c: &mut [f64]) {
+    c.fill(0.0);
+    for ii in (0..dim).step_by(MATMUL_TILE) {
+        for kk in (0..dim).step_by(MATMUL_TILE) {
+            for jj in (0..dim).step_by(MATMUL_TILE) {
+                let j_end = (jj + MATMUL_TILE).min(dim);
+                for i in ii..(ii + MATMUL_TILE).min(dim) {
+                    for k in kk..(kk + MATMUL_TILE).min(dim) {
+                        let a_ik = a[i * dim + k];
+                        let b_row = &b[k * dim + jj..k * dim + j_end];
+                        let c_row = &mut c[i * dim + jj..i * dim + j_end];
+                        for (r, &b_kj) in c_row.iter_mut().zip(b_row) {
+                            *r += a_ik * b_kj;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn bench_matmul(dim: usize) {
+    let mut rng = Rng::new(54321);
+    let mut random_matrix = || -> Vec<f64> {
+        (0..dim * dim)
+            .map(|_| (rng.next() >> 11) as f64 / (1u64 << 53) as f64)
+            .collect()
+    };
+    let (a, b) = (random_matrix(), random_matrix());
+    let gflop = 2.0 * (dim * dim * dim) as f64 / 1e9;
+
+    let matmul_seq = |c: &mut Vec<f64>| {
+        for (i, row) in c.chunks_mut(dim).enumerate() {
+            matmul_row(&a[i * dim..(i + 1) * dim], &b, dim, row);
+        }
+    };
+    let matmul_par = |c: &mut Vec<f64>| {
+        c.par_chunks_mut(dim)
+            .enumerate()
+            .for_each(|(i, row)| matmul_row(&a[i * dim..(i + 1) * dim], &b, dim, row));
+    };
+
+    let seq_ms = time_with_setup_ms(|| vec![0.0; dim * dim], matmul_seq);
+    let blocked_ms =
+        time_with_setup_ms(|| vec![0.0; dim * dim], |c| matmul_blocked(&a, &b, dim, c));
+    let par = time_par_with_setup_ms(|| vec![0.0; dim * dim], matmul_par);
+
+    // Verify correctness against the i-k-j result
+    let mut expected = vec![0.0; dim * dim];
+    matmul_seq(&mut expected);
+    let mut blocked = vec![0.0; dim * dim];
+    matmul_blocked(&a, &b, dim, &mut blocked);
+    let mut actual = vec![0.0; dim * dim];
+    matmul_par(&mut actual);
+    let close = |x: &[f64]| {
+        x.iter()
+            .zip(&expected)
+            .all(|(x, e)| (x - e).abs() <= 1e-9 * e.abs())
+    };
+    let correct = close(&blocked) && close(&actual);
+
+    println!("  {}x{} f64:", dim, dim);
+    println!(
+        "{} (correct={}) {}",
+        speedup_line("vs i-k-j:", seq_ms, &par),
+        correct,
+        rate_note("GFLOP/s", gflop, seq_ms, &par)
+    );
+    println!(
+        "{} {}",
+        speedup_line("vs blocked:", blocked_ms, &par),
+        rate_note("GFLOP/s", gflop, blocked_ms, &par)
+    );
+}
+
+// ============================================================================
+// Matrix Transpose
+// ============================================================================
+
+const TRANSPOSE_DIM: usize = 4096;
+const TRANSPOSE_TILE: usize = 32;
+
+/// Writes rows `row0..` of `dst` (the transpose of `src`) one element at a
+/// time: sequential writes, but reads stride `dim` floats through `src`.
+fn transpose_rows_naive(src: &[f32], dim: usize, row0: usize, dst_rows: &mut [f32]) {
+    for (r, dst_row) in dst_rows.chunks_mut(dim).enumerate() {
+        let i = row0 + r;
+        for (j, d) in dst_row.iter_mut().enumerate() {
+            *d = src[j * dim + i];
+        }
+    }
+}
+
+/// Same rows as `transpose_rows_naive`, copied in `TRANSPOSE_TILE` square
+/// tiles so both the source and destination lines stay in cache.
+fn transpose_rows_blocked(src: &[f32], dim: usize, row0: usize, dst_rows: &mut [f32]) {
+    let rows = dst_rows.len() / dim;
+    for ii in (0..rows).step_by(TRANSPOSE_TILE) {
+        for jj in (0..dim).step_by(TRANSPOSE_TILE) {
+            for i in ii..(ii + TRANSPOSE_TILE).min(rows) {
+                for j in jj..(jj + TRANSPOSE_TILE).min(dim) {
+                    dst_rows[i * dim + j] = src[j * dim + row0 + i];
+                }
+            }
+        }
+    }
+}
+
+fn bench_transpose() {
+    let dim = TRANSPOSE_DIM;
+    println!("=== Transpose ({}x{} f32) ===", dim, dim);
+
+    let src: Vec<f32> = (0..dim * dim).map(|i| i as f32).collect();
+    let mut dst = vec![0f32; dim * dim];
+    // Every element is read once and written once
+    let traffic = Bandwidth {
+        label: "Transpose",
+        bytes_per_elem: 2 * std::mem::size_of::<f32>(),
+    };
+
+    let naive_ms = time_ms(|| transpose_rows_naive(&src, dim, 0, &mut dst));
+    let blocked_ms = time_ms(|| transpose_rows_blocked(&src, dim, 0, &mut dst));
+
+    // Clear between variants so each correctness check sees only its own writes
+    dst.fill(0.0);
+    let naive_par = time_par_ms(|| {
+        dst.par_chunks_mut(dim)
+            .enumerate()
+            .for_each(|(i, row)| transpose_rows_naive(&src, dim, i, row));
+    });
+    let sampled_ok = |dst: &[f32]| {
+        let mut rng = Rng::new(54321);
+        (0..1000).all(|_| {
+            let (i, j) = ((rng.next() as usize) % dim, (rng.next() as usize) % dim);
+            dst[i * dim + j] == src[j * dim + i]
+        })
+    };
+    let naive_ok = sampled_ok(&dst);
+
+    dst.fill(0.0);
+    let blocked_par = time_par_ms(|| {
+        dst.par_chunks_mut(TRANSPOSE_TILE * dim)
+            .enumerate()
+            .for_each(|(t, rows)| transpose_rows_blocked(&src, dim, t * TRANSPOSE_TILE, rows));
+    });
+    let blocked_ok = sampled_ok(&dst);
+
+    println!(
+        "{} (correct={}) {}",
+        speedup_line("naive:", naive_ms, &naive_par),
+        naive_ok,
+        traffic.note(dim * dim, naive_ms, &naive_par)
+    );
+    println!(
+        "{} (correct={}) {}",
+        speedup_line("blocked:", blocked_ms, &blocked_par),
+        blocked_ok,
+        traffic.note(dim * dim, blocked_ms, &blocked_par)
+    );
+}
+
+// ============================================================================
+// In-place Reverse and Rotate
+// ============================================================================
+
+const PERMUTE_LEN: usize = 10_000_000;
+
+/// Rotation used by the rotate benchmark: about a third of the length, so
+/// the three reversals are of unequal size.
+const ROTATE_BY: usize = 3_333_331;
+
+/// Reverses `v` in parallel. `split_at_mut` hands out the two halves as
+/// disjoint borrows, and zipping the front with the back walked in reverse
+/// pairs each element with its mirror; an odd middle element is left alone.
+fn par_reverse<T: Send>(v: &mut [T]) {
+    let (front, back) = v.split_at_mut(v.len() / 2);
+    front
+        .par_iter_mut()
+        .zip(back.par_iter_mut().rev())
+        .for_each(|(a, b)| std::mem::swap(a, b));
+}
+
+/// `rotate_left` as three parallel reversals: each side of the split point,
+/// then the whole slice.
+fn par_rotate_left<T: Send>(v: &mut [T], mid: usize) {
+    let (left, right) = v.split_at_mut(mid);
+    rayon::join(|| par_reverse(left), || par_reverse(right));
+    par_reverse(v);
+}
+
+/// Sequential `reverse` and `rotate_left` against the parallel versions on
+/// 10M u64; each parallel result must equal the sequential one exactly.
+fn bench_reverse_rotate() {
+    println!("=== In-place Reverse and Rotate (10M u64) ===");
+
+    let original: Vec<u64> = (0..PERMUTE_LEN as u64).collect();
+    let mut data = original.clone();
+
+    let seq_ms = time_ms(|| {
+        data.reverse();
+        black_box(&data);
+    });
+    let par = time_par_ms(|| {
+        par_reverse(&mut data);
+        black_box(&data);
+    });
+    let (mut expected, mut actual) = (original.clone(), original.clone());
+    expected.reverse();
+    par_reverse(&mut actual);
+    println!(
+        "{} (correct={})",
+        speedup_line("reverse:", seq_ms, &par),
+        actual == expected
+    );
+
+    let seq_ms = time_ms(|| {
+        data.rotate_left(ROTATE_BY);
+        black_box(&data);
+    });
+    let par = time_par_ms(|| {
+        par_rotate_left(&mut data, ROTATE_BY);
+        black_box(&data);
+    });
+    let (mut expected, mut actual) = (original.clone(), original);
+    expected.rotate_left(ROTATE_BY);
+    par_rotate_left(&mut actual, ROTATE_BY);
+    println!(
+        "{} (correct={})",
+        speedup_line("rotate_left:", seq_ms, &par),
+        actual == expected
+    );
+}
+
+// ============================================================================
+// SAXPY (y = a*x + y, f32)
+// ============================================================================
+
+const SAXPY_SIZES: [usize; 2] = [1_000_000, 10_000_000];
+
+/// SAXPY reads x and y and writes y.
+const SAXPY_TRAFFIC: Bandwidth = Bandwidth {
+    label: "SAXPY",
+    bytes_per_elem: 3 * std::mem::size_of::<f32>(),
+};
+
+/// Bandwidth-bound element-wise update. Parallel is compared against both
+/// an indexed loop and a zipped iterator.
+fn bench_saxpy(n: usize) {
+    let a = 0.5f32;
+    let x: Vec<f32> = (0..n).map(|i| (i % 1000) as f32).collect();
+    let mut y: Vec<f32> = vec![1.0; n];
+
+    let loop_ms = time_ms(|| {
+        // Re-slicing to n lets the compiler drop bounds checks and vectorize
+        let (xs, ys) = (&x[..n], &mut y[..n]);
+        for i in 0..n {
+            ys[i] += a * xs[i];
+        }
+        black_box(&y);
+    });
+    let zip_ms = time_ms(|| {
+        for (yi, &xi) in y.iter_mut().zip(&x) {
+            *yi += a * xi;
+        }
+        black_box(&y);
+    });
+    let par = time_par_ms(|| {
+        y.par_iter_mut()
+            .zip(x.par_iter())
+            .for_each(|(yi, &xi)| *yi += a * xi);
+        black_box(&y);
+    });
+
+    println!("  N = {}:", n);
+    println!(
+        "{} {}",
+        speedup_line("vs loop:", loop_ms, &par),
+        SAXPY_TRAFFIC.note(n, loop_ms, &par)
+    );
+    println!(
+        "{} {}",
+        speedup_line("vs zip:", zip_ms, &par),
+        SAXPY_TRAFFIC.note(n, zip_ms, &par)
+    );
+}
+
+// ============================================================================
+// Zipped Slices (zip vs index)
+// ============================================================================
+
+/// Length of each input slice in the zipped-slice benchmark.
+const ZIP_LEN: usize = 10_000_000;
+
+/// Rounds of `synthetic_work` for a ~20ns element function.
+const ZIP_ROUNDS: usize = 20;
+
+#[inline]
+fn zip_combine(a: i64, b: i64) -> i64 {
+    synthetic_work(a ^ (b << 1), ZIP_ROUNDS)
+}
+
+/// `out[i] = f(a[i], b[i])` with splitting driven by zipped producers versus
+/// a single indexed producer over `out` that reads both inputs by index.
+fn bench_zipped_slices() {
+    println!("=== Zipped Slices (10M, ~20ns per element) ===");
+
+    let mut rng = Rng::new(54321);
+    let a: Vec<i64> = (0..ZIP_LEN).map(|_| rng.next() as i64).collect();
+    let b: Vec<i64> = (0..ZIP_LEN).map(|_| rng.next() as i64).collect();
+    let mut out = vec![0i64; ZIP_LEN];
+
+    let seq_ms = time_ms(|| {
+        for (o, (&x, &y)) in out.iter_mut().zip(a.iter().zip(&b)) {
+            *o = zip_combine(x, y);
+        }
+        black_box(&out);
+    });
+    let expected = out.clone();
+
+    let run = |out: &mut [i64], indexed: bool| {
+        if indexed {
+            out.par_iter_mut()
+                .enumerate()
+                .for_each(|(i, o)| *o = zip_combine(a[i], b[i]));
+        } else {
+            out.par_iter_mut()
+                .zip(a.par_iter().zip(b.par_iter()))
+                .for_each(|(o, (&x, &y))| *o = zip_combine(x, y));
+        }
+    };
+
+    for (label, indexed) in [("par zip:", false), ("par indexed:", true)] {
+        let par = time_par_ms(|| {
+            run(&mut out, indexed);
+            black_box(&out);
+        });
+        out.fill(0);
+        run(&mut out, indexed);
+        println!(
+            "{} (correct={}) {}",
+            speedup_line(label, seq_ms, &par),
+            out == expected,
+            per_elem_note(ZIP_LEN, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// 1D Convolution (sliding window)
+// ============================================================================
+
+/// Samples in the convolution input signal.
+const CONV_SAMPLES: usize = 10_000_000;
+
+/// Filter length; each output reads `CONV_TAPS` consecutive inputs.
+const CONV_TAPS: usize = 31;
+
+/// Output samples per parallel chunk.
+const CONV_CHUNK: usize = 64 * 1024;
+
+/// Valid-mode convolution of the outputs starting at `start`:
+/// `out[j] = sum_k signal[start + j + k] * kernel[k]`. The chunk reads
+/// `kernel.len() - 1` inputs past its own range (the halo).
+fn convolve_range(signal: &[f32], kernel: &[f32], start: usize, out: &mut [f32]) {
+    let input = &signal[start..start + out.len() + kernel.len() - 1];
+    for (j, o) in out.iter_mut().enumerate() {
+        let window = &input[j..j + kernel.len()];
+        *o = window.iter().zip(kernel).map(|(x, w)| x * w).sum();
+    }
+}
+
+/// 31-tap FIR over a 10M-sample signal: one sequential pass versus
+/// `par_chunks_mut` over the output, each chunk reading its input halo.
+fn bench_convolution() {
+    println!(
+        "=== 1D Convolution (10M f32 samples, {} taps) ===",
+        CONV_TAPS
+    );
+
+    let mut rng = Rng::new(54321);
+    let signal: Vec<f32> = (0..CONV_SAMPLES)
+        .map(|_| (rng.next() % 2000) as f32 / 1000.0 - 1.0)
+        .collect();
+    // Normalized triangular window
+    let half = CONV_TAPS / 2;
+    let raw: Vec<f32> = (0..CONV_TAPS)
+        .map(|k| (half + 1 - k.abs_diff(half)) as f32)
+        .collect();
+    let norm: f32 = raw.iter().sum();
+    let kernel: Vec<f32> = raw.iter().map(|w| w / norm).collect();
+
+    let outputs = CONV_SAMPLES - CONV_TAPS + 1;
+    let mut expected = vec![0f32; outputs];
+    let seq_ms = time_ms(|| {
+        convolve_range(&signal, &kernel, 0, &mut expected);
+        black_box(&expected);
+    });
+
+    let mut out = vec![0f32; outputs];
+    let par = time_par_ms(|| {
+        out.par_chunks_mut(CONV_CHUNK)
+            .enumerate()
+            .for_each(|(c, chunk)| convolve_range(&signal, &kernel, c * CONV_CHUNK, chunk));
+        black_box(&out);
+    });
+
+    let max_diff = out
+        .iter()
+        .zip(&expected)
+        .map(|(a, b)| (a - b).abs())
+        .fold(0f32, f32::max);
+    println!(
+        "{} (correct={}, max |diff|={:.1e}) {}",
+        speedup_line("par_chunks:", seq_ms, &par),
+        max_diff <= 1e-5,
+        max_diff,
+        rate_note("Msamples/s", outputs as f64 / 1e6, seq_ms, &par)
+    );
+}
+
+// ============================================================================
+// Compensated (Kahan) Summation
+// ============================================================================
+
+const KAHAN_LEN: usize = 10_000_000;
+
+/// Running Kahan sum; `lost` holds the negated low-order bits dropped so far.
+#[derive(Clone, Copy, Default)]
+struct Kahan {
+    sum: f32,
+    lost: f32,
+}
+
+impl Kahan {
+    fn add(mut self, x: f32) -> Self {
+        let y = x - self.lost;
+        let t = self.sum + y;
+        self.lost = (t - self.sum) - y;
+        self.sum = t;
+        self
+    }
+
+    /// Folds another partial sum in, including its lost bits.
+    fn merge(self, other: Kahan) -> Self {
+        self.add(other.sum).add(-other.lost)
+    }
+
+    fn value(self) -> f32 {
+        self.sum - self.lost
+    }
+}
+
+/// Sums 10M f32 spanning eight orders of magnitude naively and with Kahan
+/// compensation, sequentially and in parallel, printing the relative error
+/// of each against an f64 reference next to its time.
+fn bench_kahan_sum() {
+    println!("=== Kahan Summation (10M f32) ===");
+
+    let mut rng = Rng::new(54321);
+    let data: Vec<f32> = (0..KAHAN_LEN)
+        .map(|_| {
+            let mantissa = 1.0 + (rng.next() % 1000) as f32 / 1000.0;
+            let exponent = (rng.next() % 9) as i32 - 4;
+            mantissa * 10f32.powi(exponent)
+        })
+        .collect();
+    let reference: f64 = data.iter().map(|&x| x as f64).sum();
+    let rel_err = |x: f32| ((x as f64 - reference) / reference).abs();
+
+    let naive_seq = || -> f32 { data.iter().sum() };
+    let naive_par = || -> f32 { data.par_iter().sum() };
+    let kahan_seq = || -> f32 { data.iter().fold(Kahan::default(), |k, &x| k.add(x)).value() };
+    let kahan_par = || -> f32 {
+        data.par_chunks(64 * 1024)
+            .map(|c| c.iter().fold(Kahan::default(), |k, &x| k.add(x)))
+            .collect::<Vec<Kahan>>()
+            .into_iter()
+            .fold(Kahan::default(), Kahan::merge)
+            .value()
+    };
+
+    let seq_ms = time_ms(|| {
+        black_box(naive_seq());
+    });
+    let par = time_par_ms(|| {
+        black_box(naive_par());
+    });
+    println!(
+        "{} (rel err: seq {:.2e}, par {:.2e})",
+        speedup_line("naive:", seq_ms, &par),
+        rel_err(naive_seq()),
+        rel_err(naive_par())
+    );
+
+    let seq_ms = time_ms(|| {
+        black_box(kahan_seq());
+    });
+    let par = time_par_ms(|| {
+        black_box(kahan_par());
+    });
+    println!(
+        "{} (rel err: seq {:.2e}, par {:.2e})",
+        speedup_line("kahan:", seq_ms, &par),
+        rel_err(kahan_seq()),
+        rel_err(kahan_par())
+    );
+}
+
+// ============================================================================
+// SIMD-friendly Sum (f32)
+// ============================================================================
+
+const SIMD_SUM_LEN: usize = 10_000_000;
+
+/// Chunk handed to each task by the parallel eight-accumulator sum.
+const SIMD_SUM_CHUNK: usize = 64 * 1024;
+
+/// Sums with eight independent accumulators. An iterator `sum` must add in
+/// order, which serialises every add on the last one; eight lanes let the
+/// compiler keep a vector register of partial sums instead.
+fn sum_f32_lanes(data: &[f32]) -> f32 {
+    let mut acc = [0f32; 8];
+    let chunks = data.chunks_exact(8);
+    let tail: f32 = chunks.remainder().iter().sum();
+    for c in chunks {
+        for (a, &x) in acc.iter_mut().zip(c) {
+            *a += x;
+        }
+    }
+    acc.iter().sum::<f32>() + tail
+}
+
+/// 10M f32 summed four ways: iterator and eight-accumulator, each
+/// sequential and parallel. Values are mixed-sign, so each result is checked
+/// against an f64 reference relative to the sum of magnitudes.
+fn bench_simd_sum() {
+    println!("=== SIMD-friendly Sum (10M f32) ===");
+
+    let mut rng = Rng::new(54321);
+    let data: Vec<f32> = (0..SIMD_SUM_LEN)
+        .map(|_| (rng.next() >> 40) as f32 / (1u64 << 24) as f32 - 0.5)
+        .collect();
+    let reference: f64 = data.iter().map(|&x| x as f64).sum();
+    let scale: f64 = data.iter().map(|&x| x.abs() as f64).sum();
+    let close = |x: f32| (x as f64 - reference).abs() <= 1e-5 * scale;
+    let gflop = SIMD_SUM_LEN as f64 / 1e9;
+
+    let iter_seq = || -> f32 { data.iter().sum() };
+    let iter_par = || -> f32 { data.par_iter().sum() };
+    let lanes_seq = || sum_f32_lanes(&data);
+    let lanes_par = || -> f32 { data.par_chunks(SIMD_SUM_CHUNK).map(sum_f32_lanes).sum() };
+
+    let iter_ms = time_ms(|| {
+        black_box(iter_seq());
+    });
+    let par = time_par_ms(|| {
+        black_box(iter_par());
+    });
+    println!(
+        "{} (correct={}) {}",
+        speedup_line("iter sum:", iter_ms, &par),
+        close(iter_seq()) && close(iter_par()),
+        rate_note("GFLOP/s", gflop, iter_ms, &par)
+    );
+
+    let lanes_ms = time_ms(|| {
+        black_box(lanes_seq());
+    });
+    let par = time_par_ms(|| {
+        black_box(lanes_par());
+    });
+    println!(
+        "{} (correct={}) {}",
+        speedup_line("8 lanes:", lanes_ms, &par),
+        close(lanes_seq()) && close(lanes_par()),
+        rate_note("GFLOP/s", gflop, lanes_ms, &par)
+    );
+    println!(
+        "  sequential 8 lanes vs iter sum: {:.2}x",
+        iter_ms / lanes_ms
+    );
+}
+
+// ============================================================================
+// Mandelbrot (uneven rows)
+// ============================================================================
+
+const MANDEL_DIM: usize = 2048;
+const MANDEL_MAX_ITER: u16 = 256;
+
+/// Escape-time counts for row `y` of the [-2, 1] x [-1.5, 1.5] view.
+fn mandel_row(y: usize, row: &mut [u16]) {
+    let scale = 3.0 / MANDEL_DIM as f64;
+    let ci = y as f64 * scale - 1.5;
+    for (x, px) in row.iter_mut().enumerate() {
+        let cr = x as f64 * scale - 2.0;
+        let (mut zr, mut zi) = (0.0f64, 0.0f64);
+        let mut iter = 0;
+        while iter < MANDEL_MAX_ITER && zr * zr + zi * zi <= 4.0 {
+            let t = zr * zr - zi * zi + cr;
+            zi = 2.0 * zr * zi + ci;
+            zr = t;
+            iter += 1;
+        }
+        *px = iter;
+    }
+}
+
+/// Rows of the set's interior run all 256 iterations while outer rows
+/// escape almost immediately, so static row blocks leave threads idle that
+/// work stealing keeps busy.
+fn bench_mandelbrot() {
+    let dim = MANDEL_DIM;
+    println!(
+        "=== Mandelbrot ({}x{}, {} iter) ===",
+        dim, dim, MANDEL_MAX_ITER
+    );
+
+    let mut image = vec![0u16; dim * dim];
+    let checksum = |image: &[u16]| image.iter().map(|&v| v as u64).sum::<u64>();
+
+    let seq_ms = time_ms(|| {
+        for (y, row) in image.chunks_mut(dim).enumerate() {
+            mandel_row(y, row);
+        }
+    });
+    let expected = checksum(&image);
+
+    // Rayon: one task per row, stolen as needed
+    image.fill(0);
+    let par = time_par_ms(|| {
+        image
+            .par_chunks_mut(dim)
+            .enumerate()
+            .for_each(|(y, row)| mandel_row(y, row));
+    });
+    println!(
+        "{} (correct={})",
+        speedup_line("rows (steal):", seq_ms, &par),
+        checksum(&image) == expected
+    );
+
+    // Static: one contiguous block of rows per scoped thread
+    image.fill(0);
+    let par = time_par_ms(|| {
+        let rows_per_thread = dim.div_ceil(rayon::current_num_threads());
+        std::thread::scope(|s| {
+            for (t, block) in image.chunks_mut(rows_per_thread * dim).enumerate() {
+                s.spawn(move || {
+                    for (r, row) in block.chunks_mut(dim).enumerate() {
+                        mandel_row(t * rows_per_thread + r, row);
+                    }
+                });
+            }
+        });
+    });
+    println!(
+        "{} (correct={})",
+        speedup_line("static blocks:", seq_ms, &par),
+        checksum(&image) == expected
+    );
+}
+
+// ============================================================================
+// ThreadPoolBuilder Configurations
+// ============================================================================
+
+/// Builds a fresh pool for one configuration.
+type PoolConfig = Box<dyn Fn() -> ThreadPoolBuilder>;
+
+/// Pool construction (build + drop) and two steady-state workloads run via
+/// `pool.install` under several builder settings: a 10M-element sum
+/// (memory-bound) and every 4th Mandelbrot row (compute-bound, uneven).
+/// Deltas are relative to the default builder. Physical vs logical core
+/// counts only differ on SMT machines; configurations the platform cannot
+/// provide are skipped. The pinned pool runs one worker per CPU in the
+/// process's affinity mask, worker i on the i-th allowed CPU, and is reported
+/// as skipped if any worker fails to pin.
+fn bench_pool_configs() {
+    println!("=== ThreadPoolBuilder Configurations ===");
+
+    let logical = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let physical = topology::physical_cores();
+    match physical {
+        Some(p) => println!("  logical CPUs: {}, physical cores: {}", logical, p),
+        None => println!("  logical CPUs: {}, physical cores: unknown", logical),
+    }
+
+    let mut configs: Vec<(String, PoolConfig)> = vec![
+        ("default:".to_string(), Box::new(ThreadPoolBuilder::new)),
+        (
+            "stack 8MB:".to_string(),
+            Box::new(|| ThreadPoolBuilder::new().stack_size(8 << 20)),
+        ),
+    ];
+    let allowed = topology::allowed_cpus();
+    let pin_failed = Arc::new(AtomicBool::new(false));
+    if let Some(cpus) = allowed.clone() {
+        let failed = Arc::clone(&pin_failed);
+        configs.push((
+            "pinned:".to_string(),
+            Box::new(move || {
+                let cpus = cpus.clone();
+                let failed = Arc::clone(&failed);
+                ThreadPoolBuilder::new()
+                    .num_threads(cpus.len())
+                    .start_handler(move |i| {
+                        if !topology::pin_current_thread(cpus[i]) {
+                            failed.store(true, Ordering::Relaxed);
+                        }
+                    })
+            }),
+        ));
+    }
+    if let Some(p) = physical {
+        configs.push((
+            format!("physical ({}):", p),
+            Box::new(move || ThreadPoolBuilder::new().num_threads(p)),
+        ));
+    }
+    configs.push((
+        format!("logical ({}):", logical),
+        Box::new(move || ThreadPoolBuilder::new().num_threads(logical)),
+    ));
+
+    let data: Vec<u64> = (0..CHUNKED_LEN as u64).map(|i| i % 1000).collect();
+    let dim = MANDEL_DIM;
+    let mut band = vec![0u16; dim * dim.div_ceil(4)];
+
+    let mut baseline: Option<(f64, f64)> = None;
+    for (label, make) in &configs {
+        let build_ms = time_ms(|| drop(make().build().unwrap()));
+        let pool = make().build().unwrap();
+        if label == "pinned:" {
+            // Start handlers have all run once every worker took the broadcast
+            pool.broadcast(|_| ());
+            if pin_failed.load(Ordering::Relaxed) {
+                println!("  {:<15}skipped (pinning failed)", label);
+                continue;
+            }
+        }
+        let sum_ms = pool.install(|| {
+            time_ms(|| {
+                black_box(data.par_iter().sum::<u64>());
+            })
+        });
+        let mandel_ms = pool.install(|| {
+            time_ms(|| {
+                band.par_chunks_mut(dim)
+                    .enumerate()
+                    .for_each(|(k, row)| mandel_row(4 * k, row));
+            })
+        });
+        let (base_sum, base_mandel) = *baseline.get_or_insert((sum_ms, mandel_ms));
+        let delta = |ms: f64, base: f64| (ms / base - 1.0) * 100.0;
+        let mut line = format!(
+            "  {:<15}{:>2}T   build {:>7.3}ms   sum {:>8.3}ms ({:+5.1}%)",
+            label,
+            pool.current_num_threads(),
+            build_ms,
+            sum_ms,
+            delta(sum_ms, base_sum)
+        );
+        line.push_str(&format!(
+            "   mandel {:>8.3}ms ({:+5.1}%)",
+            mandel_ms,
+            delta(mandel_ms, base_mandel)
+        ));
+        if label == "pinned:" {
+            // Each worker checks it is running on the CPU its handler asked for
+            let cpus = allowed.as_deref().unwrap_or_default();
+            let on_cpu = pool.broadcast(|ctx| topology::current_cpu() == Some(cpus[ctx.index()]));
+            let pinned = on_cpu.iter().filter(|&&ok| ok).count();
+            line.push_str(&format!("   [{}/{} on their CPU]", pinned, on_cpu.len()));
+        }
+        println!("{}", line);
+    }
+    if allowed.is_none() {
+        println!("  {:<15}skipped (pinning unsupported)", "pinned:");
+    }
+}
+
+// ============================================================================
+// Nested Pool Install
+// ============================================================================
+
+/// Outer parallel items in the nested-install benchmark.
+const NESTED_ITEMS: usize = 64;
+
+/// A 10M-element sum split into 64 outer items, each summed by an inner
+/// parallel iterator: installed into a separate pool, run in the current
+/// pool, or summed sequentially. The flat line does it all in one
+/// `par_iter`. The inner pool is built once, outside the timed region.
+fn bench_nested_install() {
+    println!(
+        "=== Nested Pool Install ({} outer items, 10M u64) ===",
+        NESTED_ITEMS
+    );
+
+    let data: Vec<u64> = (0..CHUNKED_LEN as u64).map(|i| i % 1000).collect();
+    let chunk = CHUNKED_LEN.div_ceil(NESTED_ITEMS);
+    let expected: u64 = data.iter().sum();
+    let inner = ThreadPoolBuilder::new().build().unwrap();
+
+    let seq_ms = time_ms(|| {
+        black_box(data.iter().sum::<u64>());
+    });
+
+    let flat = || -> u64 { data.par_iter().sum() };
+    let install = || -> u64 {
+        data.par_chunks(chunk)
+            .map(|c| inner.install(|| c.par_iter().sum::<u64>()))
+            .sum()
+    };
+    let same_pool = || -> u64 {
+        data.par_chunks(chunk)
+            .map(|c| c.par_iter().sum::<u64>())
+            .sum()
+    };
+    let inner_seq = || -> u64 { data.par_chunks(chunk).map(|c| c.iter().sum::<u64>()).sum() };
+
+    let variants: [(&str, &(dyn Fn() -> u64 + Sync)); 4] = [
+        ("flat:", &flat),
+        ("inner install:", &install),
+        ("inner par:", &same_pool),
+        ("inner seq:", &inner_seq),
+    ];
+    for (label, run) in variants {
+        let par = time_par_ms(|| {
+            black_box(run());
+        });
+        println!(
+            "{} (correct={})",
+            speedup_line(label, seq_ms, &par),
+            run() == expected
+        );
+    }
+}
+
+// ============================================================================
+// Prime Sieve
+// ============================================================================
+
+/// Limits with their known prime counts (pi(10^7), pi(10^8)).
+const SIEVE_LIMITS: [(usize, usize); 2] = [(10_000_000, 664_579), (100_000_000, 5_761_455)];
+
+/// Numbers per segment of the segmented sieve (fits in L2).
+const SIEVE_SEGMENT: usize = 256 * 1024;
+
+/// Plain sieve of Eratosthenes; returns whether each number below `limit` is prime.
+fn sieve(limit: usize) -> Vec<bool> {
+    let mut is_prime = vec![true; limit];
+    for p in is_prime.iter_mut().take(2) {
+        *p = false;
+    }
+    let mut i = 2;
+    while i * i < limit {
+        if is_prime[i] {
+            for j in (i * i..limit).step_by(i) {
+                is_prime[j] = false;
+            }
+        }
+        i += 1;
+    }
+    is_prime
+}
+
+/// Primes in `[lo, hi)` by crossing off multiples of `base` (all primes below sqrt(hi)).
+fn count_segment(lo: usize, hi: usize, base: &[usize]) -> usize {
+    let mut is_prime = vec![true; hi - lo];
+    for &p in base {
+        if p * p >= hi {
+            break;
+        }
+        let start = (p * p).max(lo.div_ceil(p) * p);
+        for j in (start..hi).step_by(p) {
+            is_prime[j - lo] = false;
+        }
+    }
+    let mut count = is_prime.iter().filter(|&&p| p).count();
+    // 0 and 1 are not prime
+    if lo == 0 {
+        count -= (hi - lo).min(2);
+    }
+    count
+}
+
+/// Base primes up to sqrt(limit), found sequentially: the serial phase.
+fn base_primes(limit: usize) -> Vec<usize> {
+    let root = (limit as f64).sqrt() as usize + 1;
+    sieve(root + 1)
+        .iter()
+        .enumerate()
+        .filter(|&(_, &p)| p)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn count_primes_segmented(limit: usize) -> usize {
+    let base = base_primes(limit);
+    (0..limit.div_ceil(SIEVE_SEGMENT))
+        .into_par_iter()
+        .map(|s| {
+            let lo = s * SIEVE_SEGMENT;
+            count_segment(lo, (lo + SIEVE_SEGMENT).min(limit), &base)
+        })
+        .sum()
+}
+
+/// Sequential sieve vs a parallel segmented sieve. The base primes are
+/// computed sequentially before any segment starts; that phase is timed on
+/// its own because it caps the achievable speedup.
+fn bench_prime_sieve(limit: usize, known: usize) {
+    let seq_ms = time_ms(|| {
+        black_box(sieve(limit).iter().filter(|&&p| p).count());
+    });
+    let serial_ms = time_ms(|| {
+        black_box(base_primes(limit));
+    });
+    let par = time_par_ms(|| {
+        black_box(count_primes_segmented(limit));
+    });
+
+    let seq_count = sieve(limit).iter().filter(|&&p| p).count();
+    let par_count = count_primes_segmented(limit);
+    println!(
+        "{} (primes={}, correct={}, serial phase {:.3}ms)",
+        speedup_line(&format!("< {}M:", limit / 1_000_000), seq_ms, &par),
+        par_count,
+        seq_count == known && par_count == known,
+        serial_ms
+    );
+}
+
+// ============================================================================
+// N-Body Step
+// ============================================================================
+
+const NBODY_SIZES: [usize; 2] = [2048, 8192];
+const NBODY_DT: f64 = 0.01;
+const NBODY_SOFTENING: f64 = 1e-3;
+
+/// Velocity change of body `i` from gravity of every body in `pos`.
+fn nbody_accel(i: usize, pos: &[[f64; 3]], mass: &[f64]) -> [f64; 3] {
+    let p = pos[i];
+    let mut acc = [0.0; 3];
+    for (q, &m) in pos.iter().zip(mass) {
+        let d = [q[0] - p[0], q[1] - p[1], q[2] - p[2]];
+        let r2 = d[0] * d[0] + d[1] * d[1] + d[2] * d[2] + NBODY_SOFTENING;
+        let s = m / (r2 * r2.sqrt());
+        for k in 0..3 {
+            acc[k] += d[k] * s;
+        }
+    }
+    acc
+}
+
+fn nbody_update(i: usize, v: &mut [f64; 3], pos: &[[f64; 3]], mass: &[f64]) {
+    let acc = nbody_accel(i, pos, mass);
+    for k in 0..3 {
+        v[k] += acc[k] * NBODY_DT;
+    }
+}
+
+/// One all-pairs velocity update. Compute-bound with every body reading
+/// every position, so this is the suite's near-linear scaling case.
+fn bench_nbody(n: usize) {
+    let mut rng = Rng::new(54321);
+    let mut unit = || (rng.next() >> 11) as f64 / (1u64 << 53) as f64;
+    let pos: Vec<[f64; 3]> = (0..n).map(|_| [unit(), unit(), unit()]).collect();
+    let mass: Vec<f64> = (0..n).map(|_| 0.5 + unit()).collect();
+    let vel: Vec<[f64; 3]> = vec![[0.0; 3]; n];
+
+    let step_seq = |v: &mut Vec<[f64; 3]>| {
+        for (i, vi) in v.iter_mut().enumerate() {
+            nbody_update(i, vi, &pos, &mass);
+        }
+    };
+    let step_par = |v: &mut Vec<[f64; 3]>| {
+        v.par_iter_mut()
+            .enumerate()
+            .for_each(|(i, vi)| nbody_update(i, vi, &pos, &mass));
+    };
+
+    let seq_ms = time_with_setup_ms(|| vel.clone(), step_seq);
+    let par = time_par_with_setup_ms(|| vel.clone(), step_par);
+
+    // Verify correctness: total momentum after one step
+    let momentum = |v: &[[f64; 3]]| -> [f64; 3] {
+        let mut total = [0.0; 3];
+        for (vi, &m) in v.iter().zip(&mass) {
+            for k in 0..3 {
+                total[k] += vi[k] * m;
+            }
+        }
+        total
+    };
+    let (mut v_seq, mut v_par) = (vel.clone(), vel.clone());
+    step_seq(&mut v_seq);
+    step_par(&mut v_par);
+    let (p_seq, p_par) = (momentum(&v_seq), momentum(&v_par));
+    let drift = (0..3)
+        .map(|k| (p_seq[k] - p_par[k]).abs())
+        .fold(0.0, f64::max);
+    let scale = (0..3).map(|k| p_seq[k].abs()).fold(1.0, f64::max);
+
+    println!(
+        "{} (correct={})",
+        speedup_line(&format!("{} bodies:", n), seq_ms, &par),
+        drift <= 1e-9 * scale
+    );
+}
+
+// ============================================================================
+// K-Means (one Lloyd iteration)
+// ============================================================================
+
+const KMEANS_POINTS: usize = 1_000_000;
+const KMEANS_DIM: usize = 8;
+const KMEANS_K: usize = 16;
+
+type Point = [f64; KMEANS_DIM];
+
+/// Index of the centroid nearest to `p` (first one on ties).
+fn nearest_centroid(p: &Point, centroids: &[Point]) -> usize {
+    let mut best = (f64::INFINITY, 0);
+    for (c, centroid) in centroids.iter().enumerate() {
+        let d: f64 = p.iter().zip(centroid).map(|(a, b)| (a - b) * (a - b)).sum();
+        if d < best.0 {
+            best = (d, c);
+        }
+    }
+    best.1
+}
+
+/// Per-cluster coordinate sums and member counts.
+#[derive(Clone)]
+struct ClusterSums {
+    sums: Vec<Point>,
+    counts: Vec<usize>,
+}
+
+impl ClusterSums {
+    fn new() -> Self {
+        ClusterSums {
+            sums: vec![[0.0; KMEANS_DIM]; KMEANS_K],
+            counts: vec![0; KMEANS_K],
+        }
+    }
+
+    fn add(mut self, c: usize, p: &Point) -> Self {
+        for (s, x) in self.sums[c].iter_mut().zip(p) {
+            *s += x;
+        }
+        self.counts[c] += 1;
+        self
+    }
+
+    fn merge(mut self, other: ClusterSums) -> Self {
+        for c in 0..KMEANS_K {
+            for (s, x) in self.sums[c].iter_mut().zip(&other.sums[c]) {
+                *s += x;
+            }
+            self.counts[c] += other.counts[c];
+        }
+        self
+    }
+
+    fn centroids(&self) -> Vec<Point> {
+        self.sums
+            .iter()
+            .zip(&self.counts)
+            .map(|(s, &n)| s.map(|x| x / n.max(1) as f64))
+            .collect()
+    }
+}
+
+/// One assignment + update step, fully sequential vs a parallel assignment
+/// followed by either fold/reduce partial sums or one mutex per centroid.
+fn bench_kmeans() {
+    println!(
+        "=== K-Means Iteration ({} points, {}D, k={}) ===",
+        KMEANS_POINTS, KMEANS_DIM, KMEANS_K
+    );
+
+    let mut rng = Rng::new(54321);
+    let mut unit = || (rng.next() >> 11) as f64 / (1u64 << 53) as f64;
+    let points: Vec<Point> = (0..KMEANS_POINTS)
+        .map(|_| std::array::from_fn(|_| unit()))
+        .collect();
+    let centroids: Vec<Point> = points[..KMEANS_K].to_vec();
+
+    let iterate_seq = || -> (Vec<usize>, Vec<Point>) {
+        let assign: Vec<usize> = points
+            .iter()
+            .map(|p| nearest_centroid(p, &centroids))
+            .collect();
+        let sums = points
+            .iter()
+            .zip(&assign)
+            .fold(ClusterSums::new(), |acc, (p, &c)| acc.add(c, p));
+        (assign, sums.centroids())
+    };
+    let assign_par = || -> Vec<usize> {
+        points
+            .par_iter()
+            .map(|p| nearest_centroid(p, &centroids))
+            .collect()
+    };
+    let iterate_fold = || -> (Vec<usize>, Vec<Point>) {
+        let assign = assign_par();
+        let sums = points
+            .par_iter()
+            .zip(&assign)
+            .fold(ClusterSums::new, |acc, (p, &c)| acc.add(c, p))
+            .reduce(ClusterSums::new, ClusterSums::merge);
+        (assign, sums.centroids())
+    };
+    let iterate_mutex = || -> (Vec<usize>, Vec<Point>) {
+        let assign = assign_par();
+        let cells: Vec<Mutex<(Point, usize)>> = (0..KMEANS_K)
+            .map(|_| Mutex::new(([0.0; KMEANS_DIM], 0)))
+            .collect();
+        points.par_iter().zip(&assign).for_each(|(p, &c)| {
+            let mut cell = cells[c].lock().unwrap();
+            for (s, x) in cell.0.iter_mut().zip(p) {
+                *s += x;
+            }
+            cell.1 += 1;
+        });
+        let sums = cells
+            .into_iter()
+            .map(|m| m.into_inner().unwrap())
+            .map(|(s, n)| s.map(|x| x / n.max(1) as f64))
+            .collect();
+        (assign, sums)
+    };
+
+    let seq_ms = time_ms(|| {
+        black_box(iterate_seq());
+    });
+    let fold_par = time_par_ms(|| {
+        black_box(iterate_fold());
+    });
+    let mutex_par = time_par_ms(|| {
+        black_box(iterate_mutex());
+    });
+
+    // Verify correctness: assignments are deterministic, sums only up to rounding
+    let (expected, expected_centroids) = iterate_seq();
+    let close = |cs: &[Point]| {
+        cs.iter()
+            .flatten()
+            .zip(expected_centroids.iter().flatten())
+            .all(|(a, b)| (a - b).abs() <= 1e-9)
+    };
+    let (assign, cs) = iterate_fold();
+    println!(
+        "{} (correct={})",
+        speedup_line("fold/reduce:", seq_ms, &fold_par),
+        assign == expected && close(&cs)
+    );
+    let (assign, cs) = iterate_mutex();
+    println!(
+        "{} (correct={})",
+        speedup_line("mutex:", seq_ms, &mutex_par),
+        assign == expected && close(&cs)
+    );
+
+    let best = |par: &[(usize, f64)]| par.iter().map(|&(_, ms)| ms).fold(f64::INFINITY, f64::min);
+    let winner = if best(&fold_par) <= best(&mutex_par) {
+        "fold/reduce"
+    } else {
+        "mutex per centroid"
+    };
+    println!("  Update winner: {}", winner);
+}
+
+// ============================================================================
+// Graph Traversal (BFS)
+// ============================================================================
+
+const GRAPH_NODES: usize = 1_000_000;
+const GRAPH_AVG_DEGREE: usize = 16;
+
+/// Undirected graph in compressed sparse row form.
+struct Graph {
+    offsets: Vec<usize>,
+    targets: Vec<u32>,
+}
+
+impl Graph {
+    /// `nodes * avg_degree / 2` uniformly random edges, stored in both directions.
+    fn random(nodes: usize, avg_degree: usize, seed: u64) -> Graph {
+        let mut rng = Rng::new(seed);
+        let edges: Vec<(u32, u32)> = (0..nodes * avg_degree / 2)
+            .map(|_| {
+                let u = (rng.next() % nodes as u64) as u32;
+                let v = (rng.next() % nodes as u64) as u32;
+                (u, v)
+            })
+            .collect();
+
+        let mut offsets = vec![0usize; nodes + 1];
+        for &(u, v) in &edges {
+            offsets[u as usize + 1] += 1;
+            offsets[v as usize + 1] += 1;
+        }
+        for i in 0..nodes {
+            offsets[i + 1] += offsets[i];
+        }
+        let mut fill = offsets.clone();
+        let mut targets = vec![0u32; edges.len() * 2];
+        for &(u, v) in &edges {
+            targets[fill[u as usize]] = v;
+            fill[u as usize] += 1;
+            targets[fill[v as usize]] = u;
+            fill[v as usize] += 1;
+        }
+        Graph { offsets, targets }
+    }
+
+    fn nodes(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn neighbors(&self, v: u32) -> &[u32] {
+        &self.targets[self.offsets[v as usize]..self.offsets[v as usize + 1]]
+    }
+}
+
+/// Level-synchronous BFS from `root`; returns (nodes visited, edges scanned).
+fn bfs_seq(g: &Graph, root: u32) -> (usize, usize) {
+    let mut visited = vec![false; g.nodes()];
+    visited[root as usize] = true;
+    let mut frontier = vec![root];
+    let (mut nodes, mut edges) = (1, 0);
+    while !frontier.is_empty() {
+        let mut next = Vec::new();
+        for &u in &frontier {
+            for &v in g.neighbors(u) {
+                edges += 1;
+                if !visited[v as usize] {
+                    visited[v as usize] = true;
+                    next.push(v);
+                }
+            }
+        }
+        nodes += next.len();
+        frontier = next;
+    }
+    (nodes, edges)
+}
+
+/// Same traversal with each frontier expanded by `flat_map_iter`; a node
+/// joins the next frontier only if this thread set its visited bit first.
+fn bfs_par(g: &Graph, root: u32) -> (usize, usize) {
+    let visited: Vec<AtomicU64> = (0..g.nodes().div_ceil(64))
+        .map(|_| AtomicU64::new(0))
+        .collect();
+    let try_visit = |v: u32| {
+        let bit = 1u64 << (v % 64);
+        visited[v as usize / 64].fetch_or(bit, Ordering::Relaxed) & bit == 0
+    };
+    try_visit(root);
+    let mut frontier = vec![root];
+    let (mut nodes, mut edges) = (1, 0);
+    while !frontier.is_empty() {
+        edges += frontier
+            .iter()
+            .map(|&u| g.neighbors(u).len())
+            .sum::<usize>();
+        let next: Vec<u32> = frontier
+            .par_iter()
+            .flat_map_iter(|&u| g.neighbors(u).iter().copied().filter(|&v| try_visit(v)))
+            .collect();
+        nodes += next.len();
+        frontier = next;
+    }
+    (nodes, edges)
+}
+
+fn bench_bfs(g: &Graph) {
+    let seq_ms = time_ms(|| {
+        black_box(bfs_seq(g, 0));
+    });
+    let par = time_par_ms(|| {
+        black_box(bfs_par(g, 0));
+    });
+
+    let (seq_nodes, seq_edges) = bfs_seq(g, 0);
+    let (par_nodes, _) = bfs_par(g, 0);
+    println!(
+        "{} (visited={}, correct={}) {}",
+        speedup_line("BFS:", seq_ms, &par),
+        par_nodes,
+        par_nodes == seq_nodes,
+        rate_note("Medges/s", seq_edges as f64 / 1e6, seq_ms, &par)
+    );
+}
+
+// ============================================================================
+// Connected Components
+// ============================================================================
+
+/// Average degree of the second, sparser graph used for connected components,
+/// which has many components and long paths instead of one giant component.
+const SPARSE_AVG_DEGREE: usize = 2;
+
+/// Sequential union-find with path halving and union by size; returns the component count.
+fn components_union_find(g: &Graph) -> usize {
+    let n = g.nodes();
+    let mut parent: Vec<u32> = (0..n as u32).collect();
+    let mut size = vec![1u32; n];
+    fn find(parent: &mut [u32], mut x: u32) -> u32 {
+        while parent[x as usize] != x {
+            parent[x as usize] = parent[parent[x as usize] as usize];
+            x = parent[x as usize];
+        }
+        x
+    }
+    let mut components = n;
+    for u in 0..n as u32 {
+        for &v in g.neighbors(u) {
+            let (mut a, mut b) = (find(&mut parent, u), find(&mut parent, v));
+            if a != b {
+                if size[a as usize] < size[b as usize] {
+                    std::mem::swap(&mut a, &mut b);
+                }
+                parent[b as usize] = a;
+                size[a as usize] += size[b as usize];
+                components -= 1;
+            }
+        }
+    }
+    components
+}
+
+/// Min-label propagation: every round pushes each node's label across all
+/// of its edges with `fetch_min` (parallel over source nodes) until a round
+/// changes nothing. Returns (components, rounds).
+fn components_label_prop(g: &Graph) -> (usize, usize) {
+    let labels: Vec<AtomicU64> = (0..g.nodes() as u64).map(AtomicU64::new).collect();
+    let mut rounds = 0;
+    loop {
+        rounds += 1;
+        // Not any(): that would stop the round at the first change
+        let changed = (0..g.nodes() as u32)
+            .into_par_iter()
+            .map(|u| {
+                let mut changed = false;
+                let label = labels[u as usize].load(Ordering::Relaxed);
+                for &v in g.neighbors(u) {
+                    changed |= labels[v as usize].fetch_min(label, Ordering::Relaxed) > label;
+                }
+                changed
+            })
+            .reduce(|| false, |a, b| a | b);
+        if !changed {
+            break;
+        }
+    }
+    let components = labels
+        .iter()
+        .enumerate()
+        .filter(|&(i, l)| l.load(Ordering::Relaxed) == i as u64)
+        .count();
+    (components, rounds)
+}
+
+fn bench_components(g: &Graph, label: &str) {
+    let seq_ms = time_ms(|| {
+        black_box(components_union_find(g));
+    });
+    let par = time_par_ms(|| {
+        black_box(components_label_prop(g));
+    });
+
+    let expected = components_union_find(g);
+    let (components, rounds) = components_label_prop(g);
+    println!(
+        "{} (components={}, rounds={}, correct={})",
+        speedup_line(label, seq_ms, &par),
+        components,
+        rounds,
+        components == expected
+    );
+}
+
+// ============================================================================
+// Chunk Checksumming (FNV-1a)
+// ============================================================================
+
+const CHECKSUM_BYTES: usize = 100 * 1024 * 1024;
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut h = 0xcbf2_9ce4_8422_2325u64;
+    for &b in bytes {
+        h ^= b as u64;
+        h = h.wrapping_mul(0x0100_0000_01b3);
+    }
+    h
+}
+
+/// FNV-1a of every `chunk`-byte block, then FNV-1a over the digests in order.
+/// Differs from the single-pass digest but not with the thread count.
+fn chunked_digest(buf: &[u8], chunk: usize) -> u64 {
+    let digests: Vec<u64> = buf.par_chunks(chunk).map(fnv1a).collect();
+    let joined: Vec<u8> = digests.iter().flat_map(|d| d.to_le_bytes()).collect();
+    fnv1a(&joined)
+}
+
+fn bench_checksum() {
+    println!("=== Checksum (FNV-1a, 100MB) ===");
+
+    let mut rng = Rng::new(54321);
+    let buf: Vec<u8> = (0..CHECKSUM_BYTES).map(|_| rng.next() as u8).collect();
+    let traffic = Bandwidth {
+        label: "Checksum",
+        bytes_per_elem: 1,
+    };
+
+    let seq_ms = time_ms(|| {
+        black_box(fnv1a(&buf));
+    });
+
+    for chunk in [256 * 1024, 4 * 1024 * 1024] {
+        let par = time_par_ms(|| {
+            black_box(chunked_digest(&buf, chunk));
+        });
+
+        // The combined digest must not depend on how many threads computed it
+        let digests: Vec<u64> = [1, 4]
+            .iter()
+            .map(|&t| {
+                let pool = ThreadPoolBuilder::new().num_threads(t).build().unwrap();
+                pool.install(|| chunked_digest(&buf, chunk))
+            })
+            .collect();
+        assert_eq!(
+            digests[0], digests[1],
+            "chunked digest depends on thread count"
+        );
+
+        println!(
+            "{} (deterministic=true) {}",
+            speedup_line(&format!("{}KB chunks:", chunk / 1024), seq_ms, &par),
+            traffic.note(CHECKSUM_BYTES, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// Word Count
+// ============================================================================
+
+const TEXT_BYTES: usize = 100 * 1024 * 1024;
+const TEXT_CHUNK: usize = 1024 * 1024;
+
+/// Word and line counts of a byte range, plus whether it starts or ends
+/// inside a word so adjacent ranges can be merged.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct TextCounts {
+    words: usize,
+    lines: usize,
+    starts_in_word: bool,
+    ends_in_word: bool,
+}
+
+impl TextCounts {
+    fn scan(bytes: &[u8]) -> TextCounts {
+        let (mut words, mut lines, mut in_word) = (0, 0, false);
+        for &b in bytes {
+            if b == b'\n' {
+                lines += 1;
+            }
+            let is_word = !b.is_ascii_whitespace();
+            words += (is_word && !in_word) as usize;
+            in_word = is_word;
+        }
+        TextCounts {
+            words,
+            lines,
+            starts_in_word: bytes.first().is_some_and(|b| !b.is_ascii_whitespace()),
+            ends_in_word: in_word,
+        }
+    }
+
+    /// Counts of `self` followed by `next`; a word straddling the seam was
+    /// counted once on each side.
+    fn merge(self, next: TextCounts) -> TextCounts {
+        TextCounts {
+            words: self.words + next.words - (self.ends_in_word && next.starts_in_word) as usize,
+            lines: self.lines + next.lines,
+            starts_in_word: self.starts_in_word,
+            ends_in_word: next.ends_in_word,
+        }
+    }
+}
+
+/// ~100MB of words from a small vocabulary, separated by spaces and newlines.
+fn synthetic_text(bytes: usize) -> Vec<u8> {
+    const VOCAB: [&str; 16] = [
+        "the",
+        "parallel",
+        "of",
+        "rayon",
+        "and",
+        "benchmark",
+        "a",
+        "work",
+        "stealing",
+        "to",
+        "chunk",
+        "is",
+        "iterator",
+        "in",
+        "throughput",
+        "scheduler",
+    ];
+    let mut rng = Rng::new(54321);
+    let mut text = Vec::with_capacity(bytes + 16);
+    while text.len() < bytes {
+        let r = rng.next();
+        text.extend_from_slice(VOCAB[(r % 16) as usize].as_bytes());
+        text.push(if r.is_multiple_of(12) { b'\n' } else { b' ' });
+    }
+    text.truncate(bytes);
+    text
+}
+
+fn bench_word_count() {
+    println!("=== Word Count (100MB text) ===");
+
+    let text = synthetic_text(TEXT_BYTES);
+    let traffic = Bandwidth {
+        label: "Word count",
+        bytes_per_elem: 1,
+    };
+    let count_par = || {
+        text.par_chunks(TEXT_CHUNK)
+            .map(TextCounts::scan)
+            .reduce_with(TextCounts::merge)
+            .unwrap()
+    };
+
+    let seq_ms = time_ms(|| {
+        black_box(TextCounts::scan(&text));
+    });
+    let par = time_par_ms(|| {
+        black_box(count_par());
+    });
+
+    let (expected, actual) = (TextCounts::scan(&text), count_par());
+    println!(
+        "{} (words={}, lines={}, correct={}) {}",
+        speedup_line("words+lines:", seq_ms, &par),
+        actual.words,
+        actual.lines,
+        actual == expected,
+        traffic.note(TEXT_BYTES, seq_ms, &par)
+    );
+}
+
+// ============================================================================
+// CSV Parsing
+// ============================================================================
+
+const CSV_BYTES: usize = 50 * 1024 * 1024;
+const CSV_COLUMNS: usize = 10;
+
+/// Rows of `CSV_COLUMNS` comma-separated signed integers until `bytes` is reached.
+fn synthetic_csv(bytes: usize) -> Vec<u8> {
+    let mut rng = Rng::new(54321);
+    let mut csv = Vec::with_capacity(bytes + 256);
+    while csv.len() < bytes {
+        for c in 0..CSV_COLUMNS {
+            if c > 0 {
+                csv.push(b',');
+            }
+            let v = (rng.next() % 2_000_000) as i64 - 1_000_000;
+            csv.extend_from_slice(v.to_string().as_bytes());
+        }
+        csv.push(b'\n');
+    }
+    csv
+}
+
+/// Parses one `a,b,c` line of decimal integers.
+fn parse_csv_row(line: &[u8]) -> Vec<i64> {
+    line.split(|&b| b == b',')
+        .map(|field| {
+            let (neg, digits) = match field.split_first() {
+                Some((b'-', rest)) => (true, rest),
+                _ => (false, field),
+            };
+            let v = digits
+                .iter()
+                .fold(0i64, |acc, &d| acc * 10 + (d - b'0') as i64);
+            if neg {
+                -v
+            } else {
+                v
+            }
+        })
+        .collect()
+}
+
+fn csv_checksum(rows: &[Vec<i64>]) -> i64 {
+    rows.iter()
+        .flat_map(|r| r.iter().enumerate())
+        .fold(0i64, |acc, (c, &v)| {
+            acc.wrapping_add(v.wrapping_mul(c as i64 + 1))
+        })
+}
+
+fn bench_csv_parse() {
+    println!("=== CSV Parse (50MB, {} columns) ===", CSV_COLUMNS);
+
+    let csv = synthetic_csv(CSV_BYTES);
+    let parse_seq = || -> Vec<Vec<i64>> {
+        csv.split(|&b| b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(parse_csv_row)
+            .collect()
+    };
+    // Newline offsets first, then rows parsed independently
+    let parse_par = || -> Vec<Vec<i64>> {
+        let mut starts = vec![0];
+        starts.extend(
+            csv.iter()
+                .enumerate()
+                .filter(|&(_, &b)| b == b'\n')
+                .map(|(i, _)| i + 1),
+        );
+        (0..starts.len() - 1)
+            .into_par_iter()
+            .map(|r| parse_csv_row(&csv[starts[r]..starts[r + 1] - 1]))
+            .collect()
+    };
+
+    let seq_ms = time_ms(|| {
+        black_box(parse_seq());
+    });
+    let par = time_par_ms(|| {
+        black_box(parse_par());
+    });
+
+    let (expected, actual) = (parse_seq(), parse_par());
+    let correct =
+        actual.len() == expected.len() && csv_checksum(&actual) == csv_checksum(&expected);
+    println!(
+        "{} (rows={}, correct={})",
+        speedup_line("parse:", seq_ms, &par),
+        actual.len(),
+        correct
+    );
+    println!(
+        "  {}",
+        rate_note("MB/s", csv.len() as f64 / 1e6, seq_ms, &par)
+    );
+    println!(
+        "  {}",
+        rate_note("Mrows/s", actual.len() as f64 / 1e6, seq_ms, &par)
+    );
+}
+
+// ============================================================================
+// Scoped-thread baseline (std::thread::scope, equal chunks)
+// ============================================================================
+
+/// Splits `data` into one equal chunk per thread of the current pool and
+/// runs `f` on each from a scoped std thread; results come back in order.
+fn scoped_map_chunks<T: Sync, R: Send>(data: &[T], f: impl Fn(&[T]) -> R + Sync) -> Vec<R> {
+    let chunk = data.len().div_ceil(rayon::current_num_threads()).max(1);
+    let f = &f;
+    std::thread::scope(|s| {
+        let handles: Vec<_> = data.chunks(chunk).map(|c| s.spawn(move || f(c))).collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+/// Mutable counterpart of `scoped_map_chunks`; `f` also gets the chunk's
+/// starting index.
+fn scoped_for_each_chunk<T: Send>(data: &mut [T], f: impl Fn(usize, &mut [T]) + Sync) {
+    let chunk = data.len().div_ceil(rayon::current_num_threads()).max(1);
+    let f = &f;
+    std::thread::scope(|s| {
+        for (i, c) in data.chunks_mut(chunk).enumerate() {
+            s.spawn(move || f(i * chunk, c));
+        }
+    });
+}
+
+// ============================================================================
+// Parallel Sum
+// ============================================================================
+
+/// Each summed element is read once.
+const SUM_TRAFFIC: Bandwidth = Bandwidth {
+    label: "Sum",
+    bytes_per_elem: std::mem::size_of::<i64>(),
+};
+
+fn bench_parallel_sum(n: usize) {
+    // Initialize data
+    let data: Vec<i64> = (0..n).map(|i| (i % 1000) as i64).collect();
+
+    // Sequential sum
+    let seq_ms = time_ms(|| {
+        let sum: i64 = data.iter().sum();
+        black_box(sum);
+    });
+
+    // Scoped threads, one equal chunk each
+    let scoped = time_par_ms(|| {
+        let sum: i64 = scoped_map_chunks(&data, |c| c.iter().sum::<i64>())
+            .into_iter()
+            .sum();
+        black_box(sum);
+    });
+
+    // Parallel sum
+    let par = time_par_ms(|| {
+        let sum: i64 = data.par_iter().sum();
+        black_box(sum);
+    });
+
+    println!(
+        "{} {} {}",
+        speedup_line("Sum:", seq_ms, &par),
+        scoped_note(seq_ms, &scoped),
+        SUM_TRAFFIC.note(n, seq_ms, &par)
+    );
+    print_work_stats(|| {
+        black_box(tallied(data.par_iter()).sum::<i64>());
+    });
+}
+
+// ============================================================================
+// Parallel Sum - Element Types
+// ============================================================================
+
+const TYPE_SWEEP_LEN: usize = 10_000_000;
+
+/// The parallel-sum comparison over u8, u32, i64 and f64 holding the same
+/// values (`i % 251`). Narrow types pack more elements per cache line and
+/// vectorize differently, so speedup and bandwidth vary by type.
+fn bench_sum_element_types() {
+    println!("=== Parallel Sum by Element Type (10M) ===");
+
+    let expected: u64 = (0..TYPE_SWEEP_LEN as u64).map(|i| i % 251).sum();
+    bench_sum_element_type("Sum(u8)", |i| i as u8, |x| x as u64, expected);
+    bench_sum_element_type("Sum(u32)", |i| i as u32, |x| x as u64, expected);
+    bench_sum_element_type("Sum(i64)", |i| i as i64, |x| x, expected as i64);
+    // Every partial sum is an integer below 2^53, so f64 adds exactly
+    bench_sum_element_type("Sum(f64)", |i| i as f64, |x| x, expected as f64);
+}
+
+/// One row of the element-type sweep: `cast` builds each element from its
+/// logical value and `widen` lifts it into an accumulator wide enough for
+/// the 10M-element total, which must equal `expected`.
+fn bench_sum_element_type<T, A>(
+    label: &'static str,
+    cast: impl Fn(u64) -> T,
+    widen: impl Fn(T) -> A + Sync + Send + Copy,
+    expected: A,
+) where
+    T: Copy + Send + Sync,
+    A: std::iter::Sum + Send + PartialEq,
+{
+    let data: Vec<T> = (0..TYPE_SWEEP_LEN as u64).map(|i| cast(i % 251)).collect();
+    let traffic = Bandwidth {
+        label,
+        bytes_per_elem: std::mem::size_of::<T>(),
+    };
+
+    let seq_ms = time_ms(|| {
+        black_box(data.iter().map(|&x| widen(x)).sum::<A>());
+    });
+    let par = time_par_ms(|| {
+        black_box(data.par_iter().map(|&x| widen(x)).sum::<A>());
+    });
+
+    let correct = data.iter().map(|&x| widen(x)).sum::<A>() == expected
+        && data.par_iter().map(|&x| widen(x)).sum::<A>() == expected;
+    println!(
+        "{} (correct={}) {} {}",
+        speedup_line(&format!("{}:", label), seq_ms, &par),
+        correct,
+        rate_note("Melem/s", TYPE_SWEEP_LEN as f64 / 1e6, seq_ms, &par),
+        traffic.note(TYPE_SWEEP_LEN, seq_ms, &par)
+    );
+}
+
+// ============================================================================
+// Dot Product (f64)
+// ============================================================================
+
+/// Sequential vs parallel f64 dot product. The parallel sum adds in a
+/// different order, so results are compared with a relative epsilon and the
+/// absolute difference is printed.
+fn bench_dot_product(n: usize) {
+    let mut rng = Rng::new(54321);
+    let mut unit = || (rng.next() >> 11) as f64 / (1u64 << 53) as f64;
+    let a: Vec<f64> = (0..n).map(|_| unit()).collect();
+    let b: Vec<f64> = (0..n).map(|_| unit()).collect();
+    let gflop = 2.0 * n as f64 / 1e9;
+
+    let dot_seq = || -> f64 { a.iter().zip(&b).map(|(x, y)| x * y).sum() };
+    let dot_par = || -> f64 { a.par_iter().zip(&b).map(|(x, y)| x * y).sum() };
+
+    let seq_ms = time_ms(|| {
+        black_box(dot_seq());
+    });
+    let par = time_par_ms(|| {
+        black_box(dot_par());
+    });
+
+    let (expected, actual) = (dot_seq(), dot_par());
+    let diff = (actual - expected).abs();
+    let correct = diff <= 1e-12 * expected.abs().max(1.0);
+
+    println!(
+        "{} (correct={}, |diff|={:.3e}) {}",
+        speedup_line("Dot(f64):", seq_ms, &par),
+        correct,
+        diff,
+        rate_note("GFLOP/s", gflop, seq_ms, &par)
+    );
+}
+
+// ============================================================================
+// Parallel Map
+// ============================================================================
+
+fn bench_parallel_map(n: usize) {
+    // Skip 10M for memory
+    if n > 1_000_000 {
+        return;
+    }
+    match work_rounds() {
+        0 => bench_parallel_map_with(n, |x| x * 2 + 1),
+        rounds => bench_parallel_map_with(n, move |x| synthetic_work(x * 2 + 1, rounds)),
+    }
+}
+
+fn bench_parallel_map_with(n: usize, body: impl Fn(i64) -> i64 + Sync + Send + Copy) {
+    let input: Vec<i64> = (0..n).map(|i| i as i64).collect();
+
+    // Sequential map
+    let seq_ms = time_ms(|| {
+        let result: Vec<i64> = input.iter().map(|&x| body(x)).collect();
+        black_box(result);
+    });
+
+    // Scoped threads, each writing its chunk of the output
+    let scoped = time_par_ms(|| {
+        let mut result = vec![0i64; n];
+        scoped_for_each_chunk(&mut result, |offset, out| {
+            for (o, &x) in out.iter_mut().zip(&input[offset..]) {
+                *o = body(x);
+            }
+        });
+        black_box(result);
+    });
+
+    // Parallel map
+    let par = time_par_ms(|| {
+        let result: Vec<i64> = input.par_iter().map(|&x| body(x)).collect();
+        black_box(result);
+    });
+
+    println!(
+        "{} {}",
+        speedup_line(&work_label("Map", "x*2+1"), seq_ms, &par),
+        scoped_note(seq_ms, &scoped)
+    );
+    print_work_stats(|| {
+        let result: Vec<i64> = tallied(input.par_iter()).map(|&x| body(x)).collect();
+        black_box(result);
+    });
+}
+
+// ============================================================================
+// Parallel Reduce (find max)
+// ============================================================================
+
+fn bench_parallel_reduce(n: usize) {
+    // Initialize with pseudo-random values
+    let mut rng = Rng::new(12345);
+    let data: Vec<i64> = (0..n).map(|_| (rng.next() % 1_000_000) as i64).collect();
+
+    // Sequential max
+    let seq_ms = time_ms(|| {
+        let max: i64 = *data.iter().max().unwrap();
+        black_box(max);
+    });
+
+    // Parallel reduce max
+    let par = time_par_ms(|| {
+        let max: i64 = data
+            .par_iter()
+            .cloned()
+            .reduce(|| i64::MIN, |a, b| a.max(b));
+        black_box(max);
+    });
+
+    println!("{}", speedup_line("Reduce(max):", seq_ms, &par));
+}
+
+// ============================================================================
+// Parallel For (write indices)
+// ============================================================================
+
+/// Each index slot is written once.
+const FOR_TRAFFIC: Bandwidth = Bandwidth {
+    label: "For",
+    bytes_per_elem: std::mem::size_of::<u64>(),
+};
+
+fn bench_parallel_for(n: usize) {
+    match work_rounds() {
+        0 => bench_parallel_for_with(n, |i| (i * 2) as u64),
+        rounds => {
+            bench_parallel_for_with(n, move |i| synthetic_work((i * 2) as i64, rounds) as u64)
+        }
+    }
+}
+
+fn bench_parallel_for_with(n: usize, body: impl Fn(usize) -> u64 + Sync + Send + Copy) {
+    let mut data: Vec<u64> = vec![0; n];
+
+    // Sequential
+    let seq_ms = time_ms(|| {
+        for (i, v) in data.iter_mut().enumerate() {
+            *v = body(i);
+        }
+        black_box(&data);
+    });
+
+    // Scoped threads
+    let scoped = time_par_ms(|| {
+        scoped_for_each_chunk(&mut data, |offset, chunk| {
+            for (i, v) in chunk.iter_mut().enumerate() {
+                *v = body(offset + i);
+            }
+        });
+        black_box(&data);
+    });
+
+    // Parallel
+    let par = time_par_ms(|| {
+        data.par_iter_mut().enumerate().for_each(|(i, v)| {
+            *v = body(i);
+        });
+        black_box(&data);
+    });
+
+    let mut line = format!(
+        "{} {}",
+        speedup_line("For(indices):", seq_ms, &par),
+        scoped_note(seq_ms, &scoped)
+    );
+    // Under --work-ns the loop is compute-bound; GB/s would not mean bandwidth
+    if work_rounds() == 0 {
+        line.push_str(&format!(" {}", FOR_TRAFFIC.note(data.len(), seq_ms, &par)));
+    }
+    println!("{}", line);
+}
+
+// ============================================================================
+// Parallel Sort
+// ============================================================================
+
+/// Measured times of one sort variant, shared by all sort benchmarks so
+/// they can be printed individually and compared against each other.
+struct SortTiming {
+    seq_ms: f64,
+    par: Vec<(usize, f64)>,
+    sorted: bool,
+}
+
+impl SortTiming {
+    /// Times `seq_sort` and `par_sort` on fresh copies of `original` (the copy
+    /// is made outside the timed region) and checks the parallel result with
+    /// `in_order` over every adjacent pair.
+    fn measure<T: Clone + Send + Sync>(
+        original: &[T],
+        seq_sort: impl Fn(&mut Vec<T>) + Sync,
+        par_sort: impl Fn(&mut Vec<T>) + Sync,
+        in_order: impl Fn(&T, &T) -> bool,
+    ) -> SortTiming {
+        let seq_ms = time_with_setup_ms(|| original.to_vec(), |data| seq_sort(data));
+        let par = time_par_with_setup_ms(|| original.to_vec(), |data| par_sort(data));
+
+        // Verify correctness
+        let mut test_data = original.to_vec();
+        par_sort(&mut test_data);
+        let sorted = test_data.windows(2).all(|w| in_order(&w[0], &w[1]));
+
+        SortTiming {
+            seq_ms,
+            par,
+            sorted,
+        }
+    }
+
+    fn line(&self, label: &str) -> String {
+        format!(
+            "{} (sorted={})",
+            speedup_line(label, self.seq_ms, &self.par),
+            self.sorted
+        )
+    }
+}
+
+/// Stable and unstable sorts of the same input.
+struct SortPair {
+    stable: SortTiming,
+    unstable: SortTiming,
+}
+
+impl SortPair {
+    fn measure(original: &[i64]) -> SortPair {
+        SortPair {
+            stable: SortTiming::measure(original, |d| d.sort(), |d| d.par_sort(), |a, b| a <= b),
+            unstable: SortTiming::measure(
+                original,
+                |d| d.sort_unstable(),
+                |d| d.par_sort_unstable(),
+                |a, b| a <= b,
+            ),
+        }
+    }
+
+    /// Stable time over unstable time, sequential and per pool: what
+    /// stability costs (above 1.00x) for this input.
+    fn stability_line(&self) -> String {
+        let mut line = format!(
+            "  {:<15}seq {:>7.2}x",
+            "Stable cost:",
+            self.stable.seq_ms / self.unstable.seq_ms
+        );
+        for (&(threads, stable_ms), &(_, unstable_ms)) in
+            self.stable.par.iter().zip(&self.unstable.par)
+        {
+            if pools().is_empty() {
+                line.push_str(&format!("   par {:>7.2}x", stable_ms / unstable_ms));
+            } else {
+                line.push_str(&format!(
+                    "   {:>2}T {:>5.2}x",
+                    threads,
+                    stable_ms / unstable_ms
+                ));
+            }
+        }
+        line
+    }
+}
+
+fn bench_parallel_sort(n: usize) {
+    let mut rng = Rng::new(54321);
+    let original: Vec<i64> = (0..n).map(|_| rng.next() as i64).collect();
+
+    let pair = SortPair::measure(&original);
+    println!("{}", pair.stable.line("Sort:"));
+    println!("{}", pair.unstable.line("SortUnstable:"));
+    println!("{}", pair.stability_line());
+}
+
+fn bench_parallel_sort_by_key(n: usize) {
+    let mut rng = Rng::new(54321);
+    let original: Vec<i64> = (0..n).map(|_| rng.next() as i64).collect();
+
+    let timing = SortTiming::measure(
+        &original,
+        |d| d.sort_by_key(|&x| x),
+        |d| d.par_sort_by_key(|&x| x),
+        |a, b| a <= b,
+    );
+    println!("{}", timing.line("SortByKey:"));
+}
+
+/// A 32-byte record: sort key plus an opaque payload that moves with it.
+type Record = (u64, [u8; 24]);
+
+fn bench_parallel_sort_records(n: usize) {
+    let mut rng = Rng::new(54321);
+    let original: Vec<Record> = (0..n)
+        .map(|_| {
+            let key = rng.next();
+            let mut payload = [0u8; 24];
+            for chunk in payload.chunks_mut(8) {
+                chunk.copy_from_slice(&rng.next().to_le_bytes());
+            }
+            (key, payload)
+        })
+        .collect();
+
+    let timing = SortTiming::measure(
+        &original,
+        |d| d.sort_by_key(|r| r.0),
+        |d| d.par_sort_by_key(|r| r.0),
+        |a, b| a.0 <= b.0,
+    );
+    println!("{}", timing.line("SortRecords:"));
+}
+
+/// Unstable sort of 8-32 byte strings: pointer moves and byte-wise compares
+/// instead of register-sized keys.
+fn bench_parallel_sort_strings(n: usize) {
+    let mut rng = Rng::new(54321);
+    let original: Vec<String> = (0..n)
+        .map(|_| {
+            let len = 8 + (rng.next() % 25) as usize;
+            random_alnum(&mut rng, len)
+        })
+        .collect();
+
+    let timing = SortTiming::measure(
+        &original,
+        |d| d.sort_unstable(),
+        |d| d.par_sort_unstable(),
+        |a, b| a <= b,
+    );
+    println!("{}", timing.line("SortStrings:"));
+}
+
+/// Sorted 0..n with 2% of positions swapped with a neighbour at most 8 slots ahead,
+/// like appending slightly out-of-order entries to a sorted log.
+fn nearly_sorted(n: usize, rng: &mut Rng) -> Vec<i64> {
+    let mut data: Vec<i64> = (0..n as i64).collect();
+    if n < 2 {
+        return data;
+    }
+    for _ in 0..n / 50 {
+        let i = (rng.next() % (n as u64 - 1)) as usize;
+        let j = (i + 1 + (rng.next() % 8) as usize).min(n - 1);
+        data.swap(i, j);
+    }
+    data
+}
+
+/// Sorts over non-random input shapes. Already-sorted input is where run
+/// detection makes the sequential sorts near-linear and parallelism has little to win.
+fn bench_sort_patterns(n: usize) {
+    let mut rng = Rng::new(54321);
+    let patterns: Vec<(&str, Vec<i64>)> = vec![
+        ("sorted", (0..n as i64).collect()),
+        ("reverse", (0..n as i64).rev().collect()),
+        (
+            "100 distinct",
+            (0..n).map(|_| (rng.next() % 100) as i64).collect(),
+        ),
+        ("nearly sorted (2%)", nearly_sorted(n, &mut rng)),
+    ];
+
+    for (name, original) in &patterns {
+        println!("  Sort input: {}", name);
+        let pair = SortPair::measure(original);
+        println!("{}", pair.stable.line("stable:"));
+        println!("{}", pair.unstable.line("unstable:"));
+        println!("{}", pair.stability_line());
+    }
+}
+
+/// Mixed-case alphanumeric string of `len` characters.
+fn random_alnum(rng: &mut Rng, len: usize) -> String {
+    const ALNUM: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+    (0..len)
+        .map(|_| ALNUM[(rng.next() % ALNUM.len() as u64) as usize] as char)
+        .collect()
+}
+
+/// Sort strings by an allocating key (`to_lowercase`), recomputed per
+/// comparison with `*_by_key` and computed once per element with `*_by_cached_key`.
+fn bench_parallel_sort_cached_key(n: usize) {
+    let mut rng = Rng::new(54321);
+    let original: Vec<String> = (0..n).map(|_| random_alnum(&mut rng, 16)).collect();
+    let in_order = |a: &String, b: &String| a.to_lowercase() <= b.to_lowercase();
+
+    println!("  Sort strings by to_lowercase():");
+
+    // Key recomputed on every comparison
+    let by_key = SortTiming::measure(
+        &original,
+        |d| d.sort_by_key(|s| s.to_lowercase()),
+        |d| d.par_sort_by_key(|s| s.to_lowercase()),
+        in_order,
+    );
+    println!("{}", by_key.line("by_key:"));
+
+    // Key computed once per element
+    let cached = SortTiming::measure(
+        &original,
+        |d| d.sort_by_cached_key(|s| s.to_lowercase()),
+        |d| d.par_sort_by_cached_key(|s| s.to_lowercase()),
+        in_order,
+    );
+    println!("{}", cached.line("by_cached_key:"));
+}
+
+/// Sequential selection vs parallel sort-then-index. Rayon has no parallel
+/// selection, so this finds the size where sorting everything in parallel
+/// overtakes `select_nth_unstable`.
+fn bench_parallel_median(n: usize) {
+    let mut rng = Rng::new(54321);
+    let original: Vec<i64> = (0..n).map(|_| rng.next() as i64).collect();
+    let mid = n / 2;
+
+    let seq_ms = time_with_setup_ms(
+        || original.clone(),
+        |data| {
+            black_box(*data.select_nth_unstable(mid).1);
+        },
+    );
+    let sort_par = time_par_with_setup_ms(
+        || original.clone(),
+        |data| {
+            data.par_sort_unstable();
+            black_box(data[mid]);
+        },
+    );
+    let sort_by_par = time_par_with_setup_ms(
+        || original.clone(),
+        |data| {
+            data.par_sort_unstable_by(|a, b| a.cmp(b));
+            black_box(data[mid]);
+        },
+    );
+
+    // Verify correctness: the element at rank n/2 is unique, whatever n's parity
+    let expected = *original.clone().select_nth_unstable(mid).1;
+    let mut sorted = original.clone();
+    sorted.par_sort_unstable();
+    let mut sorted_by = original.clone();
+    sorted_by.par_sort_unstable_by(|a, b| a.cmp(b));
+
+    println!(
+        "{} (correct={})",
+        speedup_line("sort+index:", seq_ms, &sort_par),
+        sorted[mid] == expected
+    );
+    println!(
+        "{} (correct={})",
+        speedup_line("sort_by+index:", seq_ms, &sort_by_par),
+        sorted_by[mid] == expected
+    );
+}
+
+// ============================================================================
+// Radix Sort (u64)
+// ============================================================================
+
+const RADIX_SIZES: [usize; 2] = [1_000_000, 10_000_000];
+
+/// Counts of every 8-bit digit position, for all eight passes at once.
+type DigitCounts = Vec<[usize; 256]>;
+
+fn digit_counts(keys: &[u64]) -> DigitCounts {
+    keys.par_iter()
+        .fold(
+            || vec![[0usize; 256]; 8],
+            |mut counts, &k| {
+                for (d, c) in counts.iter_mut().enumerate() {
+                    c[((k >> (d * 8)) & 0xFF) as usize] += 1;
+                }
+                counts
+            },
+        )
+        .reduce(
+            || vec![[0usize; 256]; 8],
+            |mut a, b| {
+                for (x, y) in a.iter_mut().zip(&b) {
+                    x.iter_mut().zip(y).for_each(|(x, y)| *x += y);
+                }
+                a
+            },
+        )
+}
+
+/// LSD radix sort with 8-bit digits: one parallel counting pass (fold/reduce)
+/// for all digits, then a sequential stable scatter per digit. Digits where
+/// every key agrees are skipped.
+fn radix_sort(keys: &mut Vec<u64>) {
+    let counts = digit_counts(keys);
+    let mut buf = vec![0u64; keys.len()];
+    for (d, count) in counts.iter().enumerate() {
+        if count.contains(&keys.len()) {
+            continue;
+        }
+        let mut offsets = [0usize; 256];
+        let mut total = 0;
+        for (o, &c) in offsets.iter_mut().zip(count) {
+            *o = total;
+            total += c;
+        }
+        for &k in keys.iter() {
+            let digit = ((k >> (d * 8)) & 0xFF) as usize;
+            buf[offsets[digit]] = k;
+            offsets[digit] += 1;
+        }
+        std::mem::swap(keys, &mut buf);
+    }
+}
+
+fn bench_radix_sort(n: usize) {
+    let mut rng = Rng::new(54321);
+    let original: Vec<u64> = (0..n).map(|_| rng.next()).collect();
+    // Order-independent fingerprint of the multiset: wrapping sums of k and k^2
+    let checksum = |keys: &[u64]| {
+        keys.iter().fold((0u64, 0u64), |(s, q), &k| {
+            (s.wrapping_add(k), q.wrapping_add(k.wrapping_mul(k)))
+        })
+    };
+    let expected = checksum(&original);
+
+    let seq_ms = time_with_setup_ms(|| original.clone(), |keys| keys.sort_unstable());
+    let radix_par = time_par_with_setup_ms(|| original.clone(), radix_sort);
+    let pdq_par = time_par_with_setup_ms(|| original.clone(), |keys| keys.par_sort_unstable());
+
+    let mut sorted = original.clone();
+    radix_sort(&mut sorted);
+    let correct = sorted.windows(2).all(|w| w[0] <= w[1]) && checksum(&sorted) == expected;
+
+    println!("  N = {}:", n);
+    println!(
+        "{} (correct={})",
+        speedup_line("radix:", seq_ms, &radix_par),
+        correct
+    );
+    println!("{}", speedup_line("par_unstable:", seq_ms, &pdq_par));
+}
+
+// ============================================================================
+// Dedup via Sort
+// ============================================================================
+
+const DEDUP_SIZES: [usize; 2] = [1_000_000, 10_000_000];
+
+/// Dedup of n i64s where every value appears exactly twice: sort + dedup
+/// sequentially (baseline) and with `par_sort_unstable`, plus a sequential
+/// HashSet for reference.
+fn bench_parallel_dedup(n: usize) {
+    let mut rng = Rng::new(54321);
+    let mut original: Vec<i64> = (0..n as i64).map(|i| i / 2).collect();
+    for i in (1..n).rev() {
+        let j = (rng.next() % (i as u64 + 1)) as usize;
+        original.swap(i, j);
+    }
+
+    let seq_ms = time_with_setup_ms(
+        || original.clone(),
+        |data| {
+            data.sort_unstable();
+            data.dedup();
+        },
+    );
+    let par = time_par_with_setup_ms(
+        || original.clone(),
+        |data| {
+            data.par_sort_unstable();
+            data.dedup();
+        },
+    );
+    let set_ms = time_ms(|| {
+        let set: HashSet<i64> = original.iter().copied().collect();
+        black_box(set.len());
+    });
+
+    let mut seq = original.clone();
+    seq.sort_unstable();
+    seq.dedup();
+    let mut par_result = original.clone();
+    par_result.par_sort_unstable();
+    par_result.dedup();
+    let set_len = original.iter().copied().collect::<HashSet<i64>>().len();
+    let correct =
+        seq.len() == n.div_ceil(2) && par_result.len() == seq.len() && set_len == seq.len();
+
+    println!("  N = {}:", n);
+    println!(
+        "{} (unique={}, correct={})",
+        speedup_line("sort+dedup:", seq_ms, &par),
+        par_result.len(),
+        correct
+    );
+    println!("  {:<15}seq {:>8.3}ms", "HashSet:", set_ms);
+}
+
+// ============================================================================
+// Collect into HashMap
+// ============================================================================
+
+/// Entries in the HashMap collect benchmark.
+const MAP_ENTRIES: usize = 1_000_000;
+
+/// Builds a 1M-entry map from a parallel iterator of (key, value) pairs.
+/// rayon's `collect` gathers the pairs into per-split Vecs and inserts them
+/// on one thread; the fold variant builds one map per split and merges the
+/// smaller into the larger. std maps hash with SipHash, hashbrown with
+/// foldhash, so the hashbrown line also reflects the cheaper hasher.
+fn bench_collect_hashmap() {
+    println!("=== Collect into HashMap (1M entries) ===");
+
+    // Multiplying by an odd constant scatters 0..n without collisions
+    let keys: Vec<u64> = (0..MAP_ENTRIES as u64)
+        .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+        .collect();
+    let pair = |(i, &k): (usize, &u64)| (k, i as u64);
+
+    let seq_ms = time_ms(|| {
+        let map: HashMap<u64, u64> = keys.iter().enumerate().map(pair).collect();
+        black_box(map);
+    });
+
+    let std_collect = || -> usize {
+        keys.par_iter()
+            .enumerate()
+            .map(pair)
+            .collect::<HashMap<_, _>>()
+            .len()
+    };
+    let hb_collect = || -> usize {
+        keys.par_iter()
+            .enumerate()
+            .map(pair)
+            .collect::<hashbrown::HashMap<_, _>>()
+            .len()
+    };
+    let fold_merge = || -> usize {
+        keys.par_iter()
+            .enumerate()
+            .map(pair)
+            .fold(HashMap::new, |mut map, (k, v)| {
+                map.insert(k, v);
+                map
+            })
+            .reduce(HashMap::new, |a, b| {
+                let (mut big, small) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+                big.extend(small);
+                big
+            })
+            .len()
+    };
+
+    let variants: [(&str, &(dyn Fn() -> usize + Sync)); 3] = [
+        ("std collect:", &std_collect),
+        ("hb collect:", &hb_collect),
+        ("fold+merge:", &fold_merge),
+    ];
+    for (label, run) in variants {
+        let par = time_par_ms(|| {
+            black_box(run());
+        });
+        let len = run();
+        println!(
+            "{} (len={}, correct={}) {}",
+            speedup_line(label, seq_ms, &par),
+            len,
+            len == MAP_ENTRIES,
+            per_elem_note(MAP_ENTRIES, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// Group-by Sum into HashMap
+// ============================================================================
+
+/// (key, value) pairs aggregated by the group-by benchmark.
+const GROUP_PAIRS: usize = 10_000_000;
+
+/// Distinct keys among `GROUP_PAIRS`.
+const GROUP_KEYS: u64 = 10_000;
+
+/// Per-key sums produced by the group-by benchmark.
+type GroupSums = HashMap<u64, u64>;
+
+/// Adds `b`'s sums into `a`; the larger map is kept so fewer entries move.
+fn merge_group_sums(a: GroupSums, b: GroupSums) -> GroupSums {
+    let (mut big, small) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    for (k, v) in small {
+        *big.entry(k).or_insert(0) += v;
+    }
+    big
+}
+
+/// Per-key sums of 10M pairs over 10K keys. `collect::<HashMap<_, _>>()`
+/// keeps the last value per key rather than combining them, so the parallel
+/// versions both fold into per-split maps: one merges them pairwise with
+/// `reduce`, the other collects them and merges on the calling thread.
+fn bench_group_by() {
+    println!("=== Group-by Sum (10M pairs, 10K keys) ===");
+
+    let mut rng = Rng::new(54321);
+    let pairs: Vec<(u64, u64)> = (0..GROUP_PAIRS)
+        .map(|_| (rng.next() % GROUP_KEYS, rng.next() % 1000))
+        .collect();
+    let insert = |mut map: GroupSums, &(k, v): &(u64, u64)| {
+        *map.entry(k).or_insert(0) += v;
+        map
+    };
+
+    let group_seq = || pairs.iter().fold(HashMap::new(), insert);
+    let expected = group_seq();
+    let seq_ms = time_ms(|| {
+        black_box(group_seq());
+    });
+
+    let fold_reduce = || -> GroupSums {
+        pairs
+            .par_iter()
+            .fold(HashMap::new, insert)
+            .reduce(HashMap::new, merge_group_sums)
+    };
+    let fold_collect = || -> GroupSums {
+        pairs
+            .par_iter()
+            .fold(HashMap::new, insert)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .fold(HashMap::new(), merge_group_sums)
+    };
+
+    let variants: [(&str, &(dyn Fn() -> GroupSums + Sync)); 2] = [
+        ("fold+reduce:", &fold_reduce),
+        ("fold+collect:", &fold_collect),
+    ];
+    for (label, run) in variants {
+        let par = time_par_ms(|| {
+            black_box(run());
+        });
+        println!(
+            "{} (correct={}) {}",
+            speedup_line(label, seq_ms, &par),
+            run() == expected,
+            per_elem_note(GROUP_PAIRS, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// par_extend (Vec / hashbrown HashSet)
+// ============================================================================
+
+/// Elements appended in the `par_extend` benchmark.
+const EXTEND_LEN: usize = 1_000_000;
+
+/// Appends 1M mapped elements to an empty Vec and an empty hashbrown set,
+/// once starting from `new()` and once with the full capacity reserved.
+/// The destination is created outside the timed region either way.
+fn bench_par_extend() {
+    println!("=== par_extend (1M elements) ===");
+
+    let data: Vec<u64> = (0..EXTEND_LEN as u64).collect();
+    let scatter = |&x: &u64| x.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+
+    for reserved in [false, true] {
+        println!("  {}:", if reserved { "reserved" } else { "empty" });
+        let capacity = if reserved { EXTEND_LEN } else { 0 };
+
+        let seq_ms = time_with_setup_ms(
+            || Vec::with_capacity(capacity),
+            |v: &mut Vec<u64>| v.extend(data.iter().map(scatter)),
+        );
+        let par = time_par_with_setup_ms(
+            || Vec::with_capacity(capacity),
+            |v: &mut Vec<u64>| v.par_extend(data.par_iter().map(scatter)),
+        );
+        let mut expected = Vec::with_capacity(capacity);
+        expected.extend(data.iter().map(scatter));
+        let mut actual = Vec::with_capacity(capacity);
+        actual.par_extend(data.par_iter().map(scatter));
+        println!(
+            "{} (correct={}) {}",
+            speedup_line("Vec:", seq_ms, &par),
+            actual == expected,
+            per_elem_note(EXTEND_LEN, seq_ms, &par)
+        );
+
+        let seq_ms = time_with_setup_ms(
+            || hashbrown::HashSet::with_capacity(capacity),
+            |set: &mut hashbrown::HashSet<u64>| set.extend(data.iter().map(scatter)),
+        );
+        let par = time_par_with_setup_ms(
+            || hashbrown::HashSet::with_capacity(capacity),
+            |set: &mut hashbrown::HashSet<u64>| set.par_extend(data.par_iter().map(scatter)),
+        );
+        let mut set = hashbrown::HashSet::with_capacity(capacity);
+        set.par_extend(data.par_iter().map(scatter));
+        println!(
+            "{} (len={}, correct={}) {}",
+            speedup_line("HashSet:", seq_ms, &par),
+            set.len(),
+            set.len() == EXTEND_LEN && expected.iter().all(|k| set.contains(k)),
+            per_elem_note(EXTEND_LEN, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// String Building (collect into String)
+// ============================================================================
+
+/// Records formatted in the string-building benchmark (49 bytes each).
+const STRING_RECORDS: usize = 1_000_000;
+
+/// One fixed-width CSV-ish record, 48 characters plus a newline.
+fn format_record(i: usize) -> String {
+    let x = (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    format!("{:010},{:016x},{:>20.6}\n", i, x, i as f64 / 7.0)
+}
+
+/// Builds a ~50MB string from 1M formatted records: a sequential push_str
+/// loop into a pre-reserved String, rayon's `collect::<String>()`, and a fold
+/// into per-split Strings concatenated at the end. With `--features
+/// track-alloc` each line also reports the peak heap growth of one build.
+fn bench_string_building() {
+    println!("=== String Building (1M records, ~50MB) ===");
+
+    let total_len = STRING_RECORDS * format_record(0).len();
+    let mb = total_len as f64 / 1e6;
+
+    let push_loop = || -> String {
+        let mut out = String::with_capacity(total_len);
+        for i in 0..STRING_RECORDS {
+            out.push_str(&format_record(i));
+        }
+        out
+    };
+    let collect = || -> String {
+        (0..STRING_RECORDS)
+            .into_par_iter()
+            .map(format_record)
+            .collect()
+    };
+    let fold_concat = || -> String {
+        let parts: Vec<String> = (0..STRING_RECORDS)
+            .into_par_iter()
+            .fold(String::new, |mut acc, i| {
+                acc.push_str(&format_record(i));
+                acc
+            })
+            .collect();
+        parts.concat()
+    };
+
+    let (expected, seq_peak) = alloc_track::peak_during(push_loop);
+    let seq_ms = time_ms(|| {
+        black_box(push_loop());
+    });
+    let peak_note = |peak: Option<usize>| match peak {
+        Some(bytes) => format!(", peak +{:.1}MB", bytes as f64 / 1e6),
+        None => String::new(),
+    };
+    println!(
+        "  {:<15}seq {:>8.3}ms (len={}{}) {}",
+        "push_str:",
+        seq_ms,
+        expected.len(),
+        peak_note(seq_peak),
+        rate_note("MB/s", mb, seq_ms, &[])
+    );
+
+    let variants: [(&str, &(dyn Fn() -> String + Sync)); 2] =
+        [("collect:", &collect), ("fold+concat:", &fold_concat)];
+    for (label, run) in variants {
+        let par = time_par_ms(|| {
+            black_box(run());
+        });
+        let (actual, peak) = alloc_track::peak_during(run);
+        println!(
+            "{} (correct={}{}) {}",
+            speedup_line(label, seq_ms, &par),
+            actual == expected,
+            peak_note(peak),
+            rate_note("MB/s", mb, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// par_bridge (non-indexed source)
+// ============================================================================
+
+/// Items pulled from the sequential source in the `par_bridge` benchmark.
+const BRIDGE_ITEMS: usize = 1_000_000;
+
+/// Successive xorshift states: a source that can only be walked in order.
+fn rng_states(seed: u64, count: usize) -> impl Iterator<Item = u64> + Send {
+    let mut rng = Rng::new(seed);
+    std::iter::repeat_with(move || rng.next()).take(count)
+}
+
+/// ~1µs of work per item from a sequential iterator, consumed three ways:
+/// a plain loop, `par_bridge` straight off the iterator, and collecting into
+/// a Vec first so `into_par_iter` can split by index. The `collect only:` line
+/// is the sequential price of that Vec; `par_bridge` pays off only while its
+/// penalty over the indexed run stays below it.
+fn bench_par_bridge() {
+    println!("=== par_bridge (1M items, ~1us each) ===");
+
+    let rounds = expensive_rounds();
+    let work = move |x: u64| synthetic_work(x as i64, rounds);
+    let expected = rng_states(54321, BRIDGE_ITEMS)
+        .map(work)
+        .fold(0i64, |a, b| a.wrapping_add(b));
+
+    let seq_ms = time_ms(|| {
+        black_box(
+            rng_states(54321, BRIDGE_ITEMS)
+                .map(work)
+                .fold(0i64, |a, b| a.wrapping_add(b)),
+        );
+    });
+
+    let bridge = || -> i64 {
+        rng_states(54321, BRIDGE_ITEMS)
+            .par_bridge()
+            .map(work)
+            .reduce(|| 0, |a, b| a.wrapping_add(b))
+    };
+    let collect_first = || -> i64 {
+        let items: Vec<u64> = rng_states(54321, BRIDGE_ITEMS).collect();
+        items
+            .into_par_iter()
+            .map(work)
+            .reduce(|| 0, |a, b| a.wrapping_add(b))
+    };
+
+    let variants: [(&str, &(dyn Fn() -> i64 + Sync)); 2] =
+        [("par_bridge:", &bridge), ("collect+par:", &collect_first)];
+    for (label, run) in variants {
+        let par = time_par_ms(|| {
+            black_box(run());
+        });
+        println!(
+            "{} (correct={}) {}",
+            speedup_line(label, seq_ms, &par),
+            run() == expected,
+            per_elem_note(BRIDGE_ITEMS, seq_ms, &par)
+        );
+    }
+
+    let collect_ms = time_ms(|| {
+        black_box(rng_states(54321, BRIDGE_ITEMS).collect::<Vec<u64>>());
+    });
+    println!("  {:<15}seq {:>8.3}ms", "collect only:", collect_ms);
+}
+
+// ============================================================================
+// Channel Pipeline (mpsc fan-out vs par_iter)
+// ============================================================================
+
+/// Items pushed through the channel-pipeline benchmark.
+const PIPELINE_ITEMS: usize = 1_000_000;
+
+/// Bound of the `sync_channel` feeding the consumers.
+const PIPELINE_CAPACITY: usize = 1024;
+
+/// Producer busy-wait between items in the paced variant.
+const PIPELINE_PACE: Duration = Duration::from_micros(1);
+
+/// Spins for `pace`; sleeping would round up to the timer slack.
+fn spin_for(pace: Duration) {
+    let until = Instant::now() + pace;
+    while Instant::now() < until {
+        std::hint::spin_loop();
+    }
+}
+
+/// The pipeline's input, optionally arriving one item per `PIPELINE_PACE`.
+fn pipeline_source(paced: bool) -> impl Iterator<Item = u64> + Send {
+    rng_states(777, PIPELINE_ITEMS).inspect(move |_| {
+        if paced {
+            spin_for(PIPELINE_PACE);
+        }
+    })
+}
+
+/// Sends every item through a bounded channel to one consumer thread per
+/// pool thread and returns the combined result. The consumers share the
+/// receiver behind a mutex; the guard is released before `work` runs, so
+/// they only serialise on the receive itself.
+fn channel_fan_out(items: impl Iterator<Item = u64>, work: impl Fn(u64) -> i64 + Sync) -> i64 {
+    let (tx, rx) = std::sync::mpsc::sync_channel::<u64>(PIPELINE_CAPACITY);
+    let (rx, work) = (&Mutex::new(rx), &work);
+    std::thread::scope(|s| {
+        let consumers: Vec<_> = (0..rayon::current_num_threads())
+            .map(|_| {
+                s.spawn(move || {
+                    let mut total = 0i64;
+                    loop {
+                        let item = rx.lock().unwrap().recv();
+                        match item {
+                            Ok(x) => total = total.wrapping_add(work(x)),
+                            Err(_) => return total,
+                        }
+                    }
+                })
+            })
+            .collect();
+        for x in items {
+            tx.send(x).unwrap();
+        }
+        drop(tx);
+        consumers
+            .into_iter()
+            .map(|c| c.join().unwrap())
+            .fold(0, i64::wrapping_add)
+    })
+}
+
+/// 1M items of ~2µs each through a sequential loop, `par_iter`, and an mpsc
+/// fan-out to a fixed set of consumer threads. The paced variant produces
+/// one item per µs, a stream `par_iter` cannot take, so rayon is represented
+/// there by `par_bridge`.
+fn bench_channel_pipeline() {
+    println!("=== Channel Pipeline (1M items, ~2us each) ===");
+
+    let rounds = calibrate_rounds(2000);
+    let work = move |x: u64| synthetic_work(x as i64, rounds);
+    let kitems = PIPELINE_ITEMS as f64 / 1e3;
+    let sequential =
+        |paced: bool| -> i64 { pipeline_source(paced).map(work).fold(0, i64::wrapping_add) };
+    let expected = sequential(false);
+
+    println!("  unpaced:");
+    let items: Vec<u64> = pipeline_source(false).collect();
+    let seq_ms = time_adaptive_ms(|| {
+        black_box(sequential(false));
+    });
+    let par_iter = || -> i64 {
+        items
+            .par_iter()
+            .map(|&x| work(x))
+            .reduce(|| 0, i64::wrapping_add)
+    };
+    let channel = || channel_fan_out(pipeline_source(false), work);
+    let variants: [(&str, &(dyn Fn() -> i64 + Sync)); 2] =
+        [("par_iter:", &par_iter), ("channel:", &channel)];
+    for (label, run) in variants {
+        let par = on_each_pool(|| {
+            time_adaptive_ms(|| {
+                black_box(run());
+            })
+        });
+        println!(
+            "{} (correct={}) {}",
+            speedup_line(label, seq_ms, &par),
+            run() == expected,
+            rate_note("Kitems/s", kitems, seq_ms, &par)
+        );
+    }
+
+    println!("  paced (1us between items):");
+    let seq_ms = time_adaptive_ms(|| {
+        black_box(sequential(true));
+    });
+    let bridge = || -> i64 {
+        pipeline_source(true)
+            .par_bridge()
+            .map(work)
+            .reduce(|| 0, i64::wrapping_add)
+    };
+    let channel = || channel_fan_out(pipeline_source(true), work);
+    let variants: [(&str, &(dyn Fn() -> i64 + Sync)); 2] =
+        [("par_bridge:", &bridge), ("channel:", &channel)];
+    for (label, run) in variants {
+        let par = on_each_pool(|| {
+            time_adaptive_ms(|| {
+                black_box(run());
+            })
+        });
+        println!(
+            "{} (correct={}) {}",
+            speedup_line(label, seq_ms, &par),
+            run() == expected,
+            rate_note("Kitems/s", kitems, seq_ms, &par)
+        );
+    }
+}
+
+// ============================================================================
+// Uneven Workload (work stealing vs static split)
+// ============================================================================
+
+/// Elements in the uneven-workload benchmark.
+const UNEVEN_LEN: usize = 100_000;
+
+/// One element in this many is an outlier costing 100x the mean.
+const UNEVEN_OUTLIER_EVERY: u64 = 1000;
+
+/// Per-element `synthetic_work` rounds: exponential with a mean of
+/// `expensive_rounds()` (~1µs), plus rare ~100µs outliers.
+fn uneven_costs(n: usize, rng: &mut Rng) -> Vec<usize> {
+    let mean = expensive_rounds();
+    (0..n)
+        .map(|_| {
+            if rng.next().is_multiple_of(UNEVEN_OUTLIER_EVERY) {
+                return 100 * mean;
+            }
+            let u = (rng.next() >> 11) as f64 / (1u64 << 53) as f64;
+            (-(1.0 - u).ln() * mean as f64) as usize
+        })
+        .collect()
+}
+
+/// Splits `costs` into one equal-length range per thread on scoped std
+/// threads, so the slowest range decides the total.
+fn static_split_sum(costs: &[usize], threads: usize) -> i64 {
+    let chunk = costs.len().div_ceil(threads);
+    std::thread::scope(|s| {
+        let handles: Vec<_> = costs
+            .chunks(chunk)
+            .enumerate()
+            .map(|(c, part)| {
+                s.spawn(move || {
+                    part.iter().enumerate().fold(0i64, |acc, (j, &r)| {
+                        acc.wrapping_add(synthetic_work((c * chunk + j) as i64, r))
+                    })
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .fold(0i64, |acc, h| acc.wrapping_add(h.join().unwrap()))
+    })
+}
+
+/// Exponentially distributed element costs with rare outliers: rayon's work
+/// stealing against a static equal-count split on the same thread count.
+/// The `static loss:` line is the time the static split loses to stragglers.
+fn bench_uneven_workload() {
+    println!("=== Uneven Workload (100K elements, exp(~1us) + 100us outliers) ===");
+
+    let costs = uneven_costs(UNEVEN_LEN, &mut Rng::new(54321));
+    let run_seq = || -> i64 {
+        costs.iter().enumerate().fold(0i64, |acc, (i, &r)| {
+            acc.wrapping_add(synthetic_work(i as i64, r))
+        })
+    };
+    let run_rayon = || -> i64 {
+        costs
+            .par_iter()
+            .enumerate()
+            .map(|(i, &r)| synthetic_work(i as i64, r))
+            .reduce(|| 0, |a, b| a.wrapping_add(b))
+    };
+    let expected = run_seq();
+
+    let seq_ms = time_ms(|| {
+        black_box(run_seq());
+    });
+    let rayon = time_par_ms(|| {
+        black_box(run_rayon());
+    });
+    // Inside each pool, match the static split to that pool's thread count
+    let fixed = time_par_ms(|| {
+        black_box(static_split_sum(&costs, rayon::current_num_threads()));
+    });
+    let static_ok = static_split_sum(&costs, rayon::current_num_threads()) == expected;
+
+    println!(
+        "{} (correct={})",
+        speedup_line("par_iter:", seq_ms, &rayon),
+        run_rayon() == expected
+    );
+    println!(
+        "{} (correct={})",
+        speedup_line("static split:", seq_ms, &fixed),
+        static_ok
+    );
+    let gaps: Vec<String> = rayon
+        .iter()
+        .zip(&fixed)
+        .map(|(&(threads, r_ms), &(_, s_ms))| {
+            format!(
+                "{}T {:+.3}ms ({:+.1}%)",
+                threads,
+                s_ms - r_ms,
+                (s_ms / r_ms - 1.0) * 100.0
+            )
+        })
+        .collect();
+    println!("  {:<15}{}", "static loss:", gaps.join(", "));
+    print_work_stats(|| {
+        black_box(
+            tallied(costs.par_iter().enumerate())
+                .map(|(i, &r)| synthetic_work(i as i64, r))
+                .reduce(|| 0, |a, b| a.wrapping_add(b)),
+        );
+    });
+}
+
+// ============================================================================
+// Oversubscription
+// ============================================================================
+
+/// Pools of 1x, 2x and 4x the physical core count running the 10M-element
+/// sum and the uneven workload. CPU-bound work gains nothing from extra
+/// threads; the interesting columns are how far the mean and the spread
+/// across iterations move.
+fn bench_oversubscription() {
+    let logical = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let cores = topology::physical_cores().unwrap_or(logical);
+    println!("=== Oversubscription ({} physical cores) ===", cores);
+
+    let data: Vec<u64> = (0..CHUNKED_LEN as u64).map(|i| i % 1000).collect();
+    let gb = (CHUNKED_LEN * std::mem::size_of::<u64>()) as f64 / 1e9;
+    let costs = uneven_costs(UNEVEN_LEN, &mut Rng::new(54321));
+
+    for factor in [1, 2, 4] {
+        let threads = cores * factor;
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        let (sum, uneven) = pool.install(|| {
+            let sum = time_stats(|| {
+                black_box(data.par_iter().sum::<u64>());
+            });
+            let uneven = time_stats(|| {
+                let total = costs
+                    .par_iter()
+                    .enumerate()
+                    .map(|(i, &r)| synthetic_work(i as i64, r))
+                    .reduce(|| 0, |a, b| a.wrapping_add(b));
+                black_box(total);
+            });
+            (sum, uneven)
+        });
+        println!("  {}x ({}T):", factor, threads);
+        let gb_per_s = gb / (sum.mean_ms() / 1000.0);
+        println!("    {:<13}{}   {:.1} GB/s", "sum:", sum.summary(), gb_per_s);
+        println!("    {:<13}{}", "uneven:", uneven.summary());
+    }
+}
+
+// ============================================================================
+// Seq/Par Dispatch Thresholds (maybe_par)
+// ============================================================================
+
+/// A chosen path more than this much slower than the other is flagged.
+const DISPATCH_TOLERANCE: f64 = 1.25;
+
+/// Size probed when a threshold is infinite (single-threaded pool).
+const DISPATCH_FALLBACK_N: usize = 1 << 20;
+
+/// Times both paths at 0.9x and 1.1x `cutoff` (or at `DISPATCH_FALLBACK_N`
+/// when there is none) and prints which path `maybe_par` would take there,
+/// flagging sizes where that path is more than 25% slower than the other.
+/// `measure` returns seq and par times and whether the helper's result
+/// matched the sequential one.
+fn validate_threshold(
+    label: &str,
+    cutoff: usize,
+    mut measure: impl FnMut(usize) -> (f64, f64, bool),
+) {
+    let probes: Vec<(usize, &str)> = if cutoff == usize::MAX {
+        vec![(DISPATCH_FALLBACK_N, "no cutoff")]
+    } else {
+        vec![(cutoff * 9 / 10, "below"), (cutoff * 11 / 10, "above")]
+    };
+    for (n, side) in probes {
+        let (seq_ms, par_ms, correct) = measure(n);
+        let (chosen, chosen_ms) = if n >= cutoff {
+            ("par", par_ms)
+        } else {
+            ("seq", seq_ms)
+        };
+        let verdict = if chosen_ms > seq_ms.min(par_ms) * DISPATCH_TOLERANCE {
+            format!(
+                "OFF ({:.2}x slower than the other path)",
+                chosen_ms / seq_ms.min(par_ms)
+            )
+        } else {
+            "ok".to_string()
+        };
+        println!(
+            "  {:<15}n={:<9} {:<12}seq {:>8.4}ms   par {:>8.4}ms   -> {} {} (correct={})",
+            label,
+            n,
+            format!("({})", side),
+            seq_ms,
+            par_ms,
+            chosen,
+            verdict,
+            correct
+        );
+    }
+}
+
+/// Checks the `maybe_par` cutoffs for sum and sort against measurements in
+/// each pool, and whether the helpers themselves give the right answers.
+fn bench_dispatch_thresholds() {
+    println!("=== Seq/Par Dispatch Thresholds (maybe_par) ===");
+    on_each_pool(|| {
+        if !pools().is_empty() {
+            println!("  {} threads:", rayon::current_num_threads());
+        }
+        validate_threshold("sum:", maybe_par::sum_threshold(), |n| {
+            let data: Vec<i64> = (0..n).map(|i| (i % 1000) as i64).collect();
+            let correct = maybe_par::maybe_par_sum(&data) == data.iter().sum::<i64>();
+            let seq = time_adaptive_ms(|| {
+                black_box(data.iter().sum::<i64>());
+            });
+            let par = time_adaptive_ms(|| {
+                black_box(data.par_iter().sum::<i64>());
+            });
+            (seq, par, correct)
+        });
+        validate_threshold("sort:", maybe_par::sort_threshold(), |n| {
+            let mut rng = Rng::new(12345);
+            let original: Vec<i64> = (0..n).map(|_| rng.next() as i64).collect();
+            let mut sorted = original.clone();
+            maybe_par::maybe_par_sort(&mut sorted);
+            let correct = sorted.windows(2).all(|w| w[0] <= w[1]);
+            let seq = time_adaptive_with_setup_ms(|| original.clone(), |v| v.sort());
+            let par = time_adaptive_with_setup_ms(|| original.clone(), |v| v.par_sort());
+            (seq, par, correct)
+        });
+    });
+}
+
+// ============================================================================
+// Per-element Heap Allocation
+// ============================================================================
+
+const ALLOC_SIZES: [usize; 2] = [100_000, 1_000_000];
+
+/// Maps whose body allocates: one `Box<[i64; 8]>` or one small String per
+/// element, collected into a Vec and dropped inside the timed region, so
+/// both allocation and free go through the allocator from every worker.
+/// With `--features track-alloc` the allocator calls of one run are shown.
+fn bench_per_element_alloc(n: usize) {
+    let input: Vec<i64> = (0..n as i64).collect();
+    println!("  N = {}:", n);
+
+    let boxed_seq = || -> Vec<Box<[i64; 8]>> { input.iter().map(|&x| Box::new([x; 8])).collect() };
+    let boxed_par =
+        || -> Vec<Box<[i64; 8]>> { input.par_iter().map(|&x| Box::new([x; 8])).collect() };
+    let seq_ms = time_ms(|| {
+        black_box(boxed_seq());
+    });
+    let par = time_par_ms(|| {
+        black_box(boxed_par());
+    });
+    let (boxes, allocs) = alloc_track::allocations_during(boxed_par);
+    println!(
+        "{} (correct={}{})",
+        speedup_line("Box<[i64;8]>:", seq_ms, &par),
+        boxes == boxed_seq(),
+        alloc_note(allocs)
+    );
+
+    let strings_seq = || -> Vec<String> { input.iter().map(|x| x.to_string()).collect() };
+    let strings_par = || -> Vec<String> { input.par_iter().map(|x| x.to_string()).collect() };
+    let seq_ms = time_ms(|| {
+        black_box(strings_seq());
+    });
+    let par = time_par_ms(|| {
+        black_box(strings_par());
+    });
+    let (strings, allocs) = alloc_track::allocations_during(strings_par);
+    println!(
+        "{} (correct={}{})",
+        speedup_line("to_string:", seq_ms, &par),
+        strings == strings_seq(),
+        alloc_note(allocs)
+    );
+}
+
+/// `, allocs=<n>` when allocation tracking is compiled in, else nothing.
+fn alloc_note(allocs: Option<usize>) -> String {
+    allocs.map_or(String::new(), |a| format!(", allocs={}", a))
+}
+
+// ============================================================================
+// Parallel Merge
+// ============================================================================
+
+/// Below this many output elements the parallel merge falls back to `merge_seq`.
+const MERGE_SEQ_CUTOFF: usize = 4096;
+
+/// Two-pointer merge of sorted `a` and `b` into `out` (`a.len() + b.len()` long).
+fn merge_seq(a: &[i64], b: &[i64], out: &mut [i64]) {
+    let (mut i, mut j) = (0, 0);
+    for slot in out.iter_mut() {
+        if j == b.len() || (i < a.len() && a[i] <= b[j]) {
+            *slot = a[i];
+            i += 1;
+        } else {
+            *slot = b[j];
+            j += 1;
+        }
+    }
+}
+
+/// Splits the longer run at its median, binary-searches that value in the
+/// shorter run, and merges the two halves with `rayon::join`.
+fn merge_par(a: &[i64], b: &[i64], out: &mut [i64]) {
+    if out.len() <= MERGE_SEQ_CUTOFF {
+        return merge_seq(a, b, out);
+    }
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mid_a = a.len() / 2;
+    let mid_b = b.partition_point(|&x| x < a[mid_a]);
+    let (out_lo, out_hi) = out.split_at_mut(mid_a + mid_b);
+    rayon::join(
+        || merge_par(&a[..mid_a], &b[..mid_b], out_lo),
+        || merge_par(&a[mid_a..], &b[mid_b..], out_hi),
+    );
+}
+
+fn bench_parallel_merge(n: usize) {
+    let mut rng = Rng::new(54321);
+    let mut a: Vec<i64> = (0..n / 2).map(|_| rng.next() as i64).collect();
+    let mut b: Vec<i64> = (0..n - n / 2).map(|_| rng.next() as i64).collect();
+    a.sort_unstable();
+    b.sort_unstable();
+
+    // Sequential
+    let seq_ms = time_with_setup_ms(|| vec![0i64; n], |out| merge_seq(&a, &b, out));
+
+    // Parallel
+    let par = time_par_with_setup_ms(|| vec![0i64; n], |out| merge_par(&a, &b, out));
+
+    // Verify correctness
+    let mut out = vec![0i64; n];
+    merge_par(&a, &b, &mut out);
+    let is_sorted = out.len() == n && out.windows(2).all(|w| w[0] <= w[1]);
+
+    println!(
+        "{} (sorted={})",
+        speedup_line("Merge(2 runs):", seq_ms, &par),
+        is_sorted
+    );
+}
+
+// ============================================================================
+// Parallel Prefix Sum
+// ============================================================================
+
+/// Inclusive scan in place: per-chunk sums in parallel, a sequential exclusive
+/// scan of those sums, then each chunk scanned from its offset in parallel.
+fn prefix_sum_par(data: &mut [i64]) {
+    let chunk = data.len().div_ceil(rayon::current_num_threads() * 4).max(1);
+    let chunk_sums: Vec<i64> = data.par_chunks(chunk).map(|c| c.iter().sum()).collect();
+
+    let mut offsets = Vec::with_capacity(chunk_sums.len());
+    let mut running = 0i64;
+    for s in chunk_sums {
+        offsets.push(running);
+        running += s;
+    }
+
+    data.par_chunks_mut(chunk)
+        .zip(offsets)
+        .for_each(|(c, mut acc)| {
+            for x in c {
+                acc += *x;
+                *x = acc;
+            }
+        });
+}
+
+fn bench_prefix_sum(n: usize) {
+    let original: Vec<i64> = (0..n).map(|i| (i % 1000) as i64).collect();
+    let total: i64 = original.iter().sum();
+
+    // Sequential
+    let seq_ms = time_with_setup_ms(
+        || original.clone(),
+        |data| {
+            let mut acc = 0i64;
+            for x in data.iter_mut() {
+                acc += *x;
+                *x = acc;
+            }
+        },
+    );
+
+    // Parallel
+    let par = time_par_with_setup_ms(|| original.clone(), |data| prefix_sum_par(data));
+
+    // Verify correctness
+    let mut test_data = original.clone();
+    prefix_sum_par(&mut test_data);
+    let correct = test_data.last().copied().unwrap_or(0) == total;
+
+    println!(
+        "{} (correct={})",
+        speedup_line("PrefixSum:", seq_ms, &par),
+        correct
+    );
+}
+
+// ============================================================================
+// Parallel Histogram
+// ============================================================================
+
+const HISTOGRAM_BUCKETS: usize = 256;
+
+/// `n` values whose low byte (the bucket) follows Zipf(1) over the 256
+/// buckets, so the first few buckets take most of the hits.
+fn zipf_bucketed(n: usize, rng: &mut Rng) -> Vec<u64> {
+    let mut cdf = Vec::with_capacity(HISTOGRAM_BUCKETS);
+    let mut total = 0.0;
+    for r in 0..HISTOGRAM_BUCKETS {
+        total += 1.0 / (r + 1) as f64;
+        cdf.push(total);
+    }
+    (0..n)
+        .map(|_| {
+            let u = (rng.next() >> 11) as f64 / (1u64 << 53) as f64 * total;
+            let bucket = cdf.partition_point(|&c| c < u).min(HISTOGRAM_BUCKETS - 1);
+            (rng.next() << 8) | bucket as u64
+        })
+        .collect()
+}
+
+fn histogram_seq(data: &[u64]) -> Vec<u64> {
+    let mut counts = vec![0u64; HISTOGRAM_BUCKETS];
+    for &x in data {
+        counts[(x & 0xFF) as usize] += 1;
+    }
+    counts
+}
+
+/// Per-task local arrays merged pairwise at the end.
+fn histogram_fold(data: &[u64]) -> Vec<u64> {
+    data.par_iter()
+        .fold(
+            || vec![0u64; HISTOGRAM_BUCKETS],
+            |mut counts, &x| {
+                counts[(x & 0xFF) as usize] += 1;
+                counts
+            },
+        )
+        .reduce(
+            || vec![0u64; HISTOGRAM_BUCKETS],
+            |mut a, b| {
+                a.iter_mut().zip(&b).for_each(|(a, b)| *a += b);
+                a
+            },
+        )
+}
+
+/// One shared array of atomic counters, contended by every thread.
+fn histogram_atomic(data: &[u64]) -> Vec<u64> {
+    let counts: Vec<AtomicU64> = (0..HISTOGRAM_BUCKETS).map(|_| AtomicU64::new(0)).collect();
+    data.par_iter().for_each(|&x| {
+        counts[(x & 0xFF) as usize].fetch_add(1, Ordering::Relaxed);
+    });
+    counts.into_iter().map(AtomicU64::into_inner).collect()
+}
+
+fn bench_parallel_histogram(n: usize) {
+    let mut rng = Rng::new(54321);
+    let inputs: Vec<(&str, Vec<u64>)> = vec![
+        ("uniform", (0..n).map(|_| rng.next()).collect()),
+        ("zipf", zipf_bucketed(n, &mut rng)),
+    ];
+
+    for (name, data) in &inputs {
+        println!("  Histogram (256 buckets), {}:", name);
+
+        let expected = histogram_seq(data);
+        let seq_ms = time_ms(|| {
+            black_box(histogram_seq(data));
+        });
 
-        // Sort benchmarks (skip 10M due to memory)
-        if n <= 1_000_000 {
-            bench_parallel_sort(n);
-            println!();
-        }
+        let par = time_par_ms(|| {
+            black_box(histogram_fold(data));
+        });
+        let correct = histogram_fold(data) == expected;
+        println!(
+            "{} (correct={})",
+            speedup_line("fold/reduce:", seq_ms, &par),
+            correct
+        );
 
-        // Iterator benchmarks
-        bench_parallel_iter_sum(n);
-        bench_parallel_iter_map_collect(n);
-        bench_parallel_iter_filter(n);
-        println!();
+        let par = time_par_ms(|| {
+            black_box(histogram_atomic(data));
+        });
+        let correct = histogram_atomic(data) == expected;
+        println!(
+            "{} (correct={})",
+            speedup_line("atomic:", seq_ms, &par),
+            correct
+        );
     }
-
-    println!("{}", "=".repeat(80));
-    println!("Benchmark complete.");
-    println!("{}", "=".repeat(80));
-    println!();
 }
 
 // ============================================================================
-// Join Overhead
+// Parallel Search
 // ============================================================================
 
-fn bench_join_overhead() {
-    println!("=== Join Overhead ===");
+/// Rounds of `synthetic_work` for a ~50ns search predicate.
+const SEARCH_ROUNDS: usize = 25;
 
-    let iterations: usize = 100_000;
+/// Search targets at the start (0.1%), the middle, and absent (full scan).
+fn search_targets(n: usize) -> [(&'static str, i64); 3] {
+    [
+        ("start", (n / 1000) as i64),
+        ("middle", (n / 2) as i64),
+        ("absent", -1),
+    ]
+}
 
-    // Warmup
-    for _ in 0..1000 {
-        let (a, b) = rayon::join(|| 1i64, || 2i64);
-        black_box(a + b);
+fn bench_parallel_find(n: usize) {
+    let data: Vec<i64> = (0..n as i64).collect();
+
+    for (placement, target) in search_targets(n) {
+        println!("  Find (~50ns predicate), target {}:", placement);
+
+        // Compare work outputs rather than x == target so the work can't be skipped
+        let wanted = synthetic_work(target, SEARCH_ROUNDS);
+        let pred = |x: &&i64| synthetic_work(**x, SEARCH_ROUNDS) == wanted;
+        let expected = data.iter().find(pred).copied();
+
+        let seq_ms = time_ms(|| {
+            black_box(data.iter().find(pred));
+        });
+
+        let par = time_par_ms(|| {
+            black_box(data.par_iter().find_any(pred));
+        });
+        let correct = data.par_iter().find_any(pred).copied() == expected;
+        println!(
+            "{} (correct={})",
+            speedup_line("find_any:", seq_ms, &par),
+            correct
+        );
+
+        let par = time_par_ms(|| {
+            black_box(data.par_iter().find_first(pred));
+        });
+        let correct = data.par_iter().find_first(pred).copied() == expected;
+        println!(
+            "{} (correct={})",
+            speedup_line("find_first:", seq_ms, &par),
+            correct
+        );
     }
+}
 
-    // Benchmark
-    let start = Instant::now();
-    let mut count: i64 = 0;
-    for _ in 0..iterations {
-        let (a, b) = rayon::join(|| 1i64, || 2i64);
-        count += a + b;
+/// `any` and `all` (`all` sees the negated predicate, so both stop at the same
+/// element). An untimed pass counts predicate calls to show how much work
+/// the parallel version does after the answer is already known.
+fn bench_parallel_any(n: usize) {
+    let data: Vec<i64> = (0..n as i64).collect();
+    let hits = [
+        ("early", (n / 1000) as i64),
+        ("late", (n / 10 * 9) as i64),
+        ("none", -1),
+    ];
+
+    for (placement, target) in hits {
+        println!("  any/all (~50ns predicate), hit {}:", placement);
+
+        let wanted = synthetic_work(target, SEARCH_ROUNDS);
+        let is_hit = |x: &i64| synthetic_work(*x, SEARCH_ROUNDS) == wanted;
+        let seq_evals = data.iter().position(is_hit).map_or(n, |i| i + 1);
+
+        let seq_ms = time_ms(|| {
+            black_box(data.iter().any(is_hit));
+        });
+        let par = time_par_ms(|| {
+            black_box(data.par_iter().any(is_hit));
+        });
+        let evals = AtomicU64::new(0);
+        data.par_iter().any(|x| {
+            evals.fetch_add(1, Ordering::Relaxed);
+            is_hit(x)
+        });
+        println!(
+            "{} (evaluated={}/{})",
+            speedup_line("any:", seq_ms, &par),
+            evals.into_inner(),
+            seq_evals
+        );
+
+        let seq_ms = time_ms(|| {
+            black_box(data.iter().all(|x| !is_hit(x)));
+        });
+        let par = time_par_ms(|| {
+            black_box(data.par_iter().all(|x| !is_hit(x)));
+        });
+        let evals = AtomicU64::new(0);
+        data.par_iter().all(|x| {
+            evals.fetch_add(1, Ordering::Relaxed);
+            !is_hit(x)
+        });
+        println!(
+            "{} (evaluated={}/{})",
+            speedup_line("all:", seq_ms, &par),
+            evals.into_inner(),
+            seq_evals
+        );
     }
-    let elapsed_ns = start.elapsed().as_nanos() as f64;
-    let avg_ns = elapsed_ns / iterations as f64;
+}
+
+/// `position_any` / `position_first` with the target at 10%, 50% and 90%.
+/// `position_first` must return the sequential index (asserted, a harness
+/// failure otherwise); `position_any` only has to land on a matching element.
+fn bench_parallel_position(n: usize) {
+    let data: Vec<i64> = (0..n as i64).collect();
 
-    println!("Empty join: {:.1}ns avg (total={})", avg_ns, count);
+    for pct in [10, 50, 90] {
+        println!("  Position (~50ns predicate), hit at {}%:", pct);
+
+        let target = data[n * pct / 100];
+        let wanted = synthetic_work(target, SEARCH_ROUNDS);
+        let is_hit = |x: &i64| synthetic_work(*x, SEARCH_ROUNDS) == wanted;
+        let expected = data.iter().position(is_hit);
+
+        let seq_ms = time_ms(|| {
+            black_box(data.iter().position(is_hit));
+        });
+
+        let par = time_par_ms(|| {
+            black_box(data.par_iter().position_any(is_hit));
+        });
+        let any = data.par_iter().position_any(is_hit);
+        let correct = any.is_some_and(|i| data[i] == target);
+        println!(
+            "{} (correct={})",
+            speedup_line("pos_any:", seq_ms, &par),
+            correct
+        );
+
+        let par = time_par_ms(|| {
+            black_box(data.par_iter().position_first(is_hit));
+        });
+        let first = data.par_iter().position_first(is_hit);
+        assert_eq!(
+            first, expected,
+            "position_first disagrees with iter().position"
+        );
+        println!("{}", speedup_line("pos_first:", seq_ms, &par));
+    }
 }
 
 // ============================================================================
-// Parallel Sum
+// Parallel Iterator - Sum
 // ============================================================================
 
-fn bench_parallel_sum(n: usize) {
-    // Initialize data
+fn bench_parallel_iter_sum(n: usize) {
     let data: Vec<i64> = (0..n).map(|i| (i % 1000) as i64).collect();
 
-    // Warmup
-    for _ in 0..WARMUP_ITERATIONS {
-        let _: i64 = data.iter().sum();
-        let _: i64 = data.par_iter().sum();
-    }
-
-    // Sequential sum
-    let seq_start = Instant::now();
-    for _ in 0..ITERATIONS {
+    // Sequential
+    let seq_ms = time_ms(|| {
         let sum: i64 = data.iter().sum();
         black_box(sum);
-    }
-    let seq_ms = seq_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
+    });
 
-    // Parallel sum
-    let par_start = Instant::now();
-    for _ in 0..ITERATIONS {
+    // Parallel
+    let par = time_par_ms(|| {
         let sum: i64 = data.par_iter().sum();
         black_box(sum);
-    }
-    let par_ms = par_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
+    });
+
+    println!("{}", speedup_line("iter().sum():", seq_ms, &par));
+}
+
+// ============================================================================
+// Parallel Iterator - Range vs Slice
+// ============================================================================
+
+/// Value summed for index `i`; a multiply-shift so the range sum has no
+/// closed form for the compiler to find.
+#[inline]
+fn index_value(i: usize) -> u64 {
+    (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 40
+}
+
+/// The same per-index sum through three iterator shapes: a range (split by
+/// arithmetic), a slice of precomputed values (split by pointer), and
+/// `enumerate()` over a byte slice whose contents are ignored. Each shape has
+/// its own sequential baseline, so the speedups isolate splitting overhead.
+fn bench_range_vs_slice(n: usize) {
+    let values: Vec<u64> = (0..n).map(index_value).collect();
+    let dummy = vec![0u8; n];
+    let expected: u64 = values.iter().sum();
 
-    let speedup = seq_ms / par_ms;
+    let seq_ms = time_ms(|| {
+        black_box((0..n).map(index_value).sum::<u64>());
+    });
+    let range = || -> u64 { (0..n).into_par_iter().map(index_value).sum() };
+    let par = time_par_ms(|| {
+        black_box(range());
+    });
+    println!(
+        "{} (correct={})",
+        speedup_line("range:", seq_ms, &par),
+        range() == expected
+    );
+
+    let seq_ms = time_ms(|| {
+        black_box(values.iter().sum::<u64>());
+    });
+    let slice = || -> u64 { values.par_iter().sum() };
+    let par = time_par_ms(|| {
+        black_box(slice());
+    });
+    println!(
+        "{} (correct={})",
+        speedup_line("slice:", seq_ms, &par),
+        slice() == expected
+    );
 
+    let seq_ms = time_ms(|| {
+        black_box(
+            dummy
+                .iter()
+                .enumerate()
+                .map(|(i, _)| index_value(i))
+                .sum::<u64>(),
+        );
+    });
+    let enumerate = || -> u64 {
+        dummy
+            .par_iter()
+            .enumerate()
+            .map(|(i, _)| index_value(i))
+            .sum()
+    };
+    let par = time_par_ms(|| {
+        black_box(enumerate());
+    });
     println!(
-        "  Sum:           seq {:>8.3}ms   par {:>8.3}ms   {:.2}x speedup",
-        seq_ms, par_ms, speedup
+        "{} (correct={})",
+        speedup_line("enumerate:", seq_ms, &par),
+        enumerate() == expected
     );
 }
 
 // ============================================================================
-// Parallel Map
+// Parallel Iterator - Map and Collect
 // ============================================================================
 
-fn bench_parallel_map(n: usize) {
-    // Skip 10M for memory
+fn bench_parallel_iter_map_collect(n: usize) {
     if n > 1_000_000 {
         return;
     }
 
-    let input: Vec<i64> = (0..n).map(|i| i as i64).collect();
-
-    // Warmup
-    for _ in 0..WARMUP_ITERATIONS {
-        let _: Vec<i64> = input.iter().map(|&x| x * 2 + 1).collect();
-        let _: Vec<i64> = input.par_iter().map(|&x| x * 2 + 1).collect();
-    }
+    let data: Vec<i64> = (0..n).map(|i| i as i64).collect();
 
-    // Sequential map
-    let seq_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let result: Vec<i64> = input.iter().map(|&x| x * 2 + 1).collect();
+    // Sequential
+    let seq_ms = time_ms(|| {
+        let result: Vec<i64> = data.iter().map(|&x| x * x).collect();
         black_box(result);
-    }
-    let seq_ms = seq_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
+    });
 
-    // Parallel map
-    let par_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let result: Vec<i64> = input.par_iter().map(|&x| x * 2 + 1).collect();
+    // Parallel
+    let par = time_par_ms(|| {
+        let result: Vec<i64> = data.par_iter().map(|&x| x * x).collect();
         black_box(result);
-    }
-    let par_ms = par_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
-
-    let speedup = seq_ms / par_ms;
+    });
 
-    println!(
-        "  Map(x*2+1):    seq {:>8.3}ms   par {:>8.3}ms   {:.2}x speedup",
-        seq_ms, par_ms, speedup
-    );
+    println!("{}", speedup_line("map().collect:", seq_ms, &par));
 }
 
 // ============================================================================
-// Parallel Reduce (find max)
+// Parallel Iterator - Filter
 // ============================================================================
 
-fn bench_parallel_reduce(n: usize) {
-    // Initialize with pseudo-random values
-    let mut rng = Rng::new(12345);
-    let data: Vec<i64> = (0..n).map(|_| (rng.next() % 1_000_000) as i64).collect();
-
-    // Warmup
-    for _ in 0..WARMUP_ITERATIONS {
-        let _: i64 = *data.iter().max().unwrap();
-        let _: i64 = data.par_iter().cloned().reduce(|| i64::MIN, |a, b| a.max(b));
+fn bench_parallel_iter_filter(n: usize) {
+    if n > 1_000_000 {
+        return;
     }
-
-    // Sequential max
-    let seq_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let max: i64 = *data.iter().max().unwrap();
-        black_box(max);
+    match work_rounds() {
+        0 => bench_parallel_iter_filter_with(n, |x| x % 2 == 0),
+        rounds => bench_parallel_iter_filter_with(n, move |x| synthetic_work(x, rounds) % 2 == 0),
     }
-    let seq_ms = seq_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
+}
 
-    // Parallel reduce max
-    let par_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let max: i64 = data.par_iter().cloned().reduce(|| i64::MIN, |a, b| a.max(b));
-        black_box(max);
-    }
-    let par_ms = par_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
+fn bench_parallel_iter_filter_with(n: usize, pred: impl Fn(i64) -> bool + Sync + Send + Copy) {
+    let data: Vec<i64> = (0..n).map(|i| i as i64).collect();
+
+    // Sequential
+    let seq_ms = time_ms(|| {
+        let result: Vec<i64> = data.iter().filter(|&&x| pred(x)).cloned().collect();
+        black_box(result);
+    });
 
-    let speedup = seq_ms / par_ms;
+    // Parallel
+    let par = time_par_ms(|| {
+        let result: Vec<i64> = data.par_iter().filter(|&&x| pred(x)).cloned().collect();
+        black_box(result);
+    });
 
     println!(
-        "  Reduce(max):   seq {:>8.3}ms   par {:>8.3}ms   {:.2}x speedup",
-        seq_ms, par_ms, speedup
+        "{}",
+        speedup_line(&work_label("filter", "even"), seq_ms, &par)
     );
 }
 
 // ============================================================================
-// Parallel For (write indices)
+// Parallel Iterator - Partition
 // ============================================================================
 
-fn bench_parallel_for(n: usize) {
-    let mut data: Vec<u64> = vec![0; n];
+/// Count matches per chunk, size both outputs exactly, then have each chunk
+/// write into its own pre-computed window of the two vectors.
+fn partition_two_pass(data: &[i64], pred: impl Fn(i64) -> bool + Sync) -> (Vec<i64>, Vec<i64>) {
+    let chunk = data.len().div_ceil(rayon::current_num_threads() * 4).max(1);
+    let counts: Vec<usize> = data
+        .par_chunks(chunk)
+        .map(|c| c.iter().filter(|&&x| pred(x)).count())
+        .collect();
+    let matching: usize = counts.iter().sum();
 
-    // Warmup
-    for _ in 0..WARMUP_ITERATIONS {
-        for (i, v) in data.iter_mut().enumerate() {
-            *v = (i * 2) as u64;
-        }
-        data.par_iter_mut().enumerate().for_each(|(i, v)| {
-            *v = (i * 2) as u64;
-        });
+    let mut yes = vec![0i64; matching];
+    let mut no = vec![0i64; data.len() - matching];
+    let (mut yes_rest, mut no_rest) = (&mut yes[..], &mut no[..]);
+    let mut windows = Vec::with_capacity(counts.len());
+    for (c, &k) in data.chunks(chunk).zip(&counts) {
+        let (y, rest) = std::mem::take(&mut yes_rest).split_at_mut(k);
+        yes_rest = rest;
+        let (m, rest) = std::mem::take(&mut no_rest).split_at_mut(c.len() - k);
+        no_rest = rest;
+        windows.push((c, y, m));
     }
 
-    // Sequential
-    let seq_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        for (i, v) in data.iter_mut().enumerate() {
-            *v = (i * 2) as u64;
+    windows.into_par_iter().for_each(|(c, y, m)| {
+        let (mut i, mut j) = (0, 0);
+        for &x in c {
+            if pred(x) {
+                y[i] = x;
+                i += 1;
+            } else {
+                m[j] = x;
+                j += 1;
+            }
         }
-        black_box(&data);
-    }
-    let seq_ms = seq_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
+    });
+    (yes, no)
+}
 
-    // Parallel
-    let par_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        data.par_iter_mut().enumerate().for_each(|(i, v)| {
-            *v = (i * 2) as u64;
-        });
-        black_box(&data);
+fn bench_parallel_partition(n: usize) {
+    if n > 1_000_000 {
+        return;
     }
-    let par_ms = par_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
 
-    let speedup = seq_ms / par_ms;
+    let mut rng = Rng::new(54321);
+    let data: Vec<i64> = (0..n).map(|_| rng.next() as i64).collect();
+    // ~50% selectivity on random bits
+    let pred = |x: i64| x & 1 == 0;
+    let (yes, no): (Vec<i64>, Vec<i64>) = data.iter().partition(|&&x| pred(x));
+
+    // Sequential
+    let seq_ms = time_ms(|| {
+        let result: (Vec<i64>, Vec<i64>) = data.iter().partition(|&&x| pred(x));
+        black_box(result);
+    });
+
+    // Parallel, rayon's partition (per-task vectors, then concatenated)
+    let par = time_par_ms(|| {
+        let result: (Vec<i64>, Vec<i64>) = data.par_iter().partition(|&&x| pred(x));
+        black_box(result);
+    });
+    let (y, m): (Vec<i64>, Vec<i64>) = data.par_iter().partition(|&&x| pred(x));
+    let correct = y.len() == yes.len() && m.len() == no.len();
+    println!(
+        "{} (correct={})",
+        speedup_line("partition:", seq_ms, &par),
+        correct
+    );
 
+    // Parallel, two-pass into pre-sized outputs
+    let par = time_par_ms(|| {
+        black_box(partition_two_pass(&data, pred));
+    });
+    let (y, m) = partition_two_pass(&data, pred);
+    let correct = y == yes && m == no;
     println!(
-        "  For(indices):  seq {:>8.3}ms   par {:>8.3}ms   {:.2}x speedup",
-        seq_ms, par_ms, speedup
+        "{} (correct={})",
+        speedup_line("partition(2p):", seq_ms, &par),
+        correct
     );
 }
 
 // ============================================================================
-// Parallel Sort
+// Parallel Iterator - Min/Max by Key
 // ============================================================================
 
-fn bench_parallel_sort(n: usize) {
-    let mut rng = Rng::new(54321);
-    let original: Vec<i64> = (0..n).map(|_| rng.next() as i64).collect();
+/// Rounds of `synthetic_work` for a ~100ns key function.
+const KEY_ROUNDS: usize = 50;
 
-    // Warmup
-    for _ in 0..WARMUP_ITERATIONS {
-        let mut data = original.clone();
-        data.sort();
-        let mut data = original.clone();
-        data.par_sort();
-    }
+/// A 32-byte element whose sort key has to be computed.
+#[derive(Clone, Copy)]
+struct Item {
+    id: u64,
+    weight: u64,
+    _payload: [u8; 16],
+}
 
-    // Sequential sort
-    let seq_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let mut data = original.clone();
-        data.sort();
-        black_box(&data);
-    }
-    let seq_ms = seq_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
+fn expensive_key(item: &Item) -> i64 {
+    synthetic_work(item.weight as i64, KEY_ROUNDS)
+}
 
-    // Parallel sort
-    let par_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let mut data = original.clone();
-        data.par_sort();
-        black_box(&data);
+fn bench_parallel_min_max_by_key(n: usize) {
+    if n > 1_000_000 {
+        return;
     }
-    let par_ms = par_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
 
-    let speedup = seq_ms / par_ms;
+    let mut rng = Rng::new(54321);
+    let items: Vec<Item> = (0..n as u64)
+        .map(|id| Item {
+            id,
+            weight: rng.next(),
+            _payload: [0; 16],
+        })
+        .collect();
 
-    // Verify correctness
-    let mut test_data = original.clone();
-    test_data.par_sort();
-    let is_sorted = test_data.windows(2).all(|w| w[0] <= w[1]);
+    // max_by_key
+    let seq_ms = time_ms(|| {
+        black_box(items.iter().max_by_key(|x| expensive_key(x)));
+    });
+    let par = time_par_ms(|| {
+        black_box(items.par_iter().max_by_key(|x| expensive_key(x)));
+    });
+    let expected = items.iter().max_by_key(|x| expensive_key(x)).map(|x| x.id);
+    let correct = items
+        .par_iter()
+        .max_by_key(|x| expensive_key(x))
+        .map(|x| x.id)
+        == expected;
+    println!(
+        "{} (correct={})",
+        speedup_line("max_by_key:", seq_ms, &par),
+        correct
+    );
 
+    // min_by_key
+    let seq_ms = time_ms(|| {
+        black_box(items.iter().min_by_key(|x| expensive_key(x)));
+    });
+    let par = time_par_ms(|| {
+        black_box(items.par_iter().min_by_key(|x| expensive_key(x)));
+    });
+    let expected = items.iter().min_by_key(|x| expensive_key(x)).map(|x| x.id);
+    let correct = items
+        .par_iter()
+        .min_by_key(|x| expensive_key(x))
+        .map(|x| x.id)
+        == expected;
     println!(
-        "  Sort:          seq {:>8.3}ms   par {:>8.3}ms   {:.2}x speedup (sorted={})",
-        seq_ms, par_ms, speedup, is_sorted
+        "{} (correct={})",
+        speedup_line("min_by_key:", seq_ms, &par),
+        correct
     );
 }
 
 // ============================================================================
-// Parallel Iterator - Sum
+// Parallel Iterator - flat_map vs flat_map_iter
 // ============================================================================
 
-fn bench_parallel_iter_sum(n: usize) {
-    let data: Vec<i64> = (0..n).map(|i| (i % 1000) as i64).collect();
+/// Values each input element expands to.
+const FLAT_FANOUT: i64 = 16;
 
-    // Warmup
-    for _ in 0..WARMUP_ITERATIONS {
-        let _: i64 = data.iter().sum();
-        let _: i64 = data.par_iter().sum();
+fn bench_parallel_flat_map(n: usize) {
+    if n > 1_000_000 {
+        return;
     }
 
-    // Sequential
-    let seq_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let sum: i64 = data.iter().sum();
-        black_box(sum);
-    }
-    let seq_ms = seq_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
+    let data: Vec<i64> = (0..n as i64).collect();
+    let expected_len = n * FLAT_FANOUT as usize;
 
-    // Parallel
-    let par_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let sum: i64 = data.par_iter().sum();
-        black_box(sum);
-    }
-    let par_ms = par_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
+    // Sequential
+    let seq_ms = time_ms(|| {
+        let result: Vec<i64> = data
+            .iter()
+            .flat_map(|&x| (0..FLAT_FANOUT).map(move |k| x * FLAT_FANOUT + k))
+            .collect();
+        black_box(result);
+    });
 
-    let speedup = seq_ms / par_ms;
+    // Parallel, inner iterators also parallel
+    let flat_map = || -> Vec<i64> {
+        data.par_iter()
+            .flat_map(|&x| {
+                (0..FLAT_FANOUT)
+                    .into_par_iter()
+                    .map(move |k| x * FLAT_FANOUT + k)
+            })
+            .collect()
+    };
+    let par = time_par_ms(|| {
+        black_box(flat_map());
+    });
+    println!(
+        "{} (correct={})",
+        speedup_line("flat_map:", seq_ms, &par),
+        flat_map().len() == expected_len
+    );
 
+    // Parallel, inner iterators sequential per item
+    let flat_map_iter = || -> Vec<i64> {
+        data.par_iter()
+            .flat_map_iter(|&x| (0..FLAT_FANOUT).map(move |k| x * FLAT_FANOUT + k))
+            .collect()
+    };
+    let par = time_par_ms(|| {
+        black_box(flat_map_iter());
+    });
     println!(
-        "  iter().sum():  seq {:>8.3}ms   par {:>8.3}ms   {:.2}x speedup",
-        seq_ms, par_ms, speedup
+        "{} (correct={})",
+        speedup_line("flat_map_iter:", seq_ms, &par),
+        flat_map_iter().len() == expected_len
     );
 }
 
 // ============================================================================
-// Parallel Iterator - Map and Collect
+// Parallel Iterator - String Case Conversion
 // ============================================================================
 
-fn bench_parallel_iter_map_collect(n: usize) {
+/// `to_uppercase` allocates a new String per element; `make_ascii_uppercase`
+/// rewrites each string in place and allocates nothing.
+fn bench_parallel_to_uppercase(n: usize) {
     if n > 1_000_000 {
         return;
     }
 
-    let data: Vec<i64> = (0..n).map(|i| i as i64).collect();
-
-    // Warmup
-    for _ in 0..WARMUP_ITERATIONS {
-        let _: Vec<i64> = data.iter().map(|&x| x * x).collect();
-        let _: Vec<i64> = data.par_iter().map(|&x| x * x).collect();
-    }
+    let mut rng = Rng::new(54321);
+    let original: Vec<String> = (0..n)
+        .map(|_| {
+            let len = 16 + (rng.next() % 49) as usize;
+            random_alnum(&mut rng, len)
+        })
+        .collect();
 
-    // Sequential
-    let seq_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let result: Vec<i64> = data.iter().map(|&x| x * x).collect();
+    // Allocating map
+    let seq_ms = time_ms(|| {
+        let result: Vec<String> = original.iter().map(|s| s.to_uppercase()).collect();
         black_box(result);
-    }
-    let seq_ms = seq_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
-
-    // Parallel
-    let par_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let result: Vec<i64> = data.par_iter().map(|&x| x * x).collect();
+    });
+    let par = time_par_ms(|| {
+        let result: Vec<String> = original.par_iter().map(|s| s.to_uppercase()).collect();
         black_box(result);
-    }
-    let par_ms = par_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
+    });
+    println!("{}", speedup_line("to_uppercase:", seq_ms, &par));
 
-    let speedup = seq_ms / par_ms;
-
-    println!(
-        "  map().collect: seq {:>8.3}ms   par {:>8.3}ms   {:.2}x speedup",
-        seq_ms, par_ms, speedup
+    // In place, on an untimed copy
+    let seq_ms = time_with_setup_ms(
+        || original.clone(),
+        |strings| strings.iter_mut().for_each(|s| s.make_ascii_uppercase()),
     );
+    let par = time_par_with_setup_ms(
+        || original.clone(),
+        |strings| {
+            strings
+                .par_iter_mut()
+                .for_each(|s| s.make_ascii_uppercase())
+        },
+    );
+    println!("{}", speedup_line("in place:", seq_ms, &par));
 }
 
 // ============================================================================
-// Parallel Iterator - Filter
+// Parallel Iterator - Per-Thread Scratch (map_init)
 // ============================================================================
 
-fn bench_parallel_iter_filter(n: usize) {
+const SCRATCH_BYTES: usize = 4096;
+
+/// Element work that needs a 4KB scratch buffer: fill it from `x`, then
+/// sample one byte per cache line.
+fn scratch_work(x: i64, scratch: &mut [u8]) -> u64 {
+    for (i, b) in scratch.iter_mut().enumerate() {
+        *b = (x as u8).wrapping_add(i as u8);
+    }
+    scratch.iter().step_by(64).map(|&b| b as u64).sum()
+}
+
+/// The same scratch-buffer work with a fresh buffer per element (`map`), one
+/// per rayon split (`map_init`), and one carried through `fold`, against a
+/// sequential loop reusing a single buffer.
+fn bench_parallel_map_init(n: usize) {
     if n > 1_000_000 {
         return;
     }
 
-    let data: Vec<i64> = (0..n).map(|i| i as i64).collect();
+    let data: Vec<i64> = (0..n as i64).collect();
+    let expected: u64 = {
+        let mut scratch = vec![0u8; SCRATCH_BYTES];
+        data.iter().map(|&x| scratch_work(x, &mut scratch)).sum()
+    };
 
-    // Warmup
-    for _ in 0..WARMUP_ITERATIONS {
-        let _: Vec<i64> = data.iter().filter(|&&x| x % 2 == 0).cloned().collect();
-        let _: Vec<i64> = data.par_iter().filter(|&&x| x % 2 == 0).cloned().collect();
+    let seq_ms = time_ms(|| {
+        let mut scratch = vec![0u8; SCRATCH_BYTES];
+        black_box(
+            data.iter()
+                .map(|&x| scratch_work(x, &mut scratch))
+                .sum::<u64>(),
+        );
+    });
+
+    let alloc_each = || -> u64 {
+        data.par_iter()
+            .map(|&x| scratch_work(x, &mut vec![0u8; SCRATCH_BYTES]))
+            .sum()
+    };
+    let map_init = || -> u64 {
+        data.par_iter()
+            .map_init(
+                || vec![0u8; SCRATCH_BYTES],
+                |scratch, &x| scratch_work(x, scratch),
+            )
+            .sum()
+    };
+    let fold = || -> u64 {
+        data.par_iter()
+            .fold(
+                || (vec![0u8; SCRATCH_BYTES], 0u64),
+                |(mut scratch, acc), &x| {
+                    let v = scratch_work(x, &mut scratch);
+                    (scratch, acc + v)
+                },
+            )
+            .map(|(_, acc)| acc)
+            .sum()
+    };
+
+    let variants: [(&str, &(dyn Fn() -> u64 + Sync)); 3] = [
+        ("map (alloc):", &alloc_each),
+        ("map_init:", &map_init),
+        ("fold:", &fold),
+    ];
+    for (label, run) in variants {
+        let par = time_par_ms(|| {
+            black_box(run());
+        });
+        println!(
+            "{} (correct={}) {}",
+            speedup_line(label, seq_ms, &par),
+            run() == expected,
+            per_elem_note(n, seq_ms, &par)
+        );
     }
+}
 
-    // Sequential
-    let seq_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let result: Vec<i64> = data.iter().filter(|&&x| x % 2 == 0).cloned().collect();
-        black_box(result);
+// ============================================================================
+// Grain Size (with_min_len)
+// ============================================================================
+
+static EXPENSIVE_ROUNDS: OnceLock<usize> = OnceLock::new();
+
+/// Rounds of `synthetic_work` for a ~1µs body, calibrated on first use.
+fn expensive_rounds() -> usize {
+    *EXPENSIVE_ROUNDS.get_or_init(|| calibrate_rounds(1000))
+}
+
+/// Dependent multiply-xorshift chain standing in for real per-element work.
+/// Every round is a bijection on u64, so distinct inputs give distinct outputs.
+#[inline(never)]
+fn synthetic_work(x: i64, rounds: usize) -> i64 {
+    let mut h = x as u64;
+    for _ in 0..rounds {
+        h = h
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        h ^= h >> 29;
     }
-    let seq_ms = seq_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
+    h as i64
+}
 
-    // Parallel
-    let par_start = Instant::now();
-    for _ in 0..ITERATIONS {
-        let result: Vec<i64> = data.par_iter().filter(|&&x| x % 2 == 0).cloned().collect();
-        black_box(result);
+/// Parallel map at fixed `with_min_len` grains, for a cheap and an expensive body.
+///
+/// `main` runs this up to 1M elements; the ~1µs body only joins up to 100K,
+/// where a sequential pass already takes ~0.1s.
+fn bench_grain_size(n: usize) {
+    const GRAINS: [Option<usize>; 5] = [Some(1), Some(64), Some(1024), Some(65536), None];
+
+    let input: Vec<i64> = (0..n).map(|i| i as i64).collect();
+    let cheap: fn(i64) -> i64 = |x| x * 2 + 1;
+    let mut bodies = vec![("cheap body (x*2+1)", cheap)];
+    if n <= 100_000 {
+        let expensive: fn(i64) -> i64 = |x| synthetic_work(x, expensive_rounds());
+        bodies.push(("~1us body", expensive));
     }
-    let par_ms = par_start.elapsed().as_secs_f64() * 1000.0 / ITERATIONS as f64;
 
-    let speedup = seq_ms / par_ms;
+    for (name, body) in bodies {
+        println!("  Grain size, {}:", name);
 
-    println!(
-        "  filter(even):  seq {:>8.3}ms   par {:>8.3}ms   {:.2}x speedup",
-        seq_ms, par_ms, speedup
-    );
+        let seq_ms = time_ms(|| {
+            let result: Vec<i64> = input.iter().map(|&x| body(x)).collect();
+            black_box(result);
+        });
+
+        for grain in GRAINS {
+            let par = time_par_ms(|| {
+                let result: Vec<i64> = match grain {
+                    Some(min_len) => input
+                        .par_iter()
+                        .with_min_len(min_len)
+                        .map(|&x| body(x))
+                        .collect(),
+                    None => input.par_iter().map(|&x| body(x)).collect(),
+                };
+                black_box(result);
+            });
+            let label = match grain {
+                Some(min_len) => format!("min_len {}:", min_len),
+                None => "default:".to_string(),
+            };
+            println!("{}", speedup_line(&label, seq_ms, &par));
+        }
+    }
 }
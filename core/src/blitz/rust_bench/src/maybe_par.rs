@@ -0,0 +1,106 @@
+//! Size-based dispatch between sequential and rayon implementations.
+//!
+//! Follows the cost model of blitz's `threshold.zig`: an operation goes
+//! parallel once its estimated work (length x cost per element) exceeds ten
+//! times the synchronisation overhead of the current pool. Rayon's overhead
+//! is taken as ~5µs per worker, the work-stealing figure `threshold.zig`
+//! quotes against blitz's heartbeat scheduler. With a single thread nothing
+//! goes parallel.
+
+use rayon::prelude::*;
+
+/// Estimated sequential cost per element in ns, as in `threshold.zig`.
+const SUM_COST_NS: usize = 2;
+const SORT_COST_NS: usize = 50;
+
+/// Synchronisation overhead per rayon worker in ns.
+const OVERHEAD_PER_WORKER_NS: usize = 5000;
+
+/// Smallest length at which an operation costing `cost_ns` per element runs
+/// in parallel on the current pool; `usize::MAX` on a single thread.
+fn threshold(cost_ns: usize) -> usize {
+    let workers = rayon::current_num_threads();
+    if workers <= 1 {
+        return usize::MAX;
+    }
+    workers * OVERHEAD_PER_WORKER_NS * 10 / cost_ns
+}
+
+/// Length from which `maybe_par_sum` uses `par_iter`.
+pub fn sum_threshold() -> usize {
+    threshold(SUM_COST_NS)
+}
+
+/// Length from which `maybe_par_sort` uses `par_sort`.
+pub fn sort_threshold() -> usize {
+    threshold(SORT_COST_NS)
+}
+
+/// Sums `data`, in parallel from `sum_threshold()` elements.
+pub fn maybe_par_sum(data: &[i64]) -> i64 {
+    if data.len() >= sum_threshold() {
+        data.par_iter().sum()
+    } else {
+        data.iter().sum()
+    }
+}
+
+/// Stable-sorts `data`, in parallel from `sort_threshold()` elements.
+pub fn maybe_par_sort<T: Ord + Send>(data: &mut [T]) {
+    if data.len() >= sort_threshold() {
+        data.par_sort();
+    } else {
+        data.sort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(threads: usize) -> rayon::ThreadPool {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn single_thread_never_goes_parallel() {
+        pool(1).install(|| {
+            assert_eq!(sum_threshold(), usize::MAX);
+            assert_eq!(sort_threshold(), usize::MAX);
+        });
+    }
+
+    #[test]
+    fn sort_goes_parallel_before_sum() {
+        pool(4).install(|| assert!(sort_threshold() < sum_threshold()));
+    }
+
+    #[test]
+    fn sum_matches_on_both_sides_of_cutoff() {
+        pool(4).install(|| {
+            let cutoff = sum_threshold();
+            for n in [cutoff - 1, cutoff, cutoff + 1] {
+                let data: Vec<i64> = (0..n as i64).collect();
+                assert_eq!(maybe_par_sum(&data), data.iter().sum::<i64>(), "n={}", n);
+            }
+        });
+    }
+
+    #[test]
+    fn sort_matches_on_both_sides_of_cutoff() {
+        pool(4).install(|| {
+            let cutoff = sort_threshold();
+            for n in [cutoff - 1, cutoff, cutoff + 1] {
+                let original: Vec<i64> = (0..n as i64).map(|i| (i * 7919) % 1000).rev().collect();
+                let mut expected = original.clone();
+                expected.sort();
+                let mut sorted = original;
+                maybe_par_sort(&mut sorted);
+                assert_eq!(sorted, expected, "n={}", n);
+            }
+        });
+    }
+}
@@ -0,0 +1,113 @@
+//! CPU topology and thread affinity.
+//!
+//! Linux reads core siblings from sysfs, the allowed CPU set with
+//! `sched_getaffinity` and pins with `sched_setaffinity`. Other platforms
+//! report neither and refuse to pin, so callers can skip those
+//! configurations.
+
+/// Number of physical cores (SMT siblings counted once), if known.
+pub fn physical_cores() -> Option<usize> {
+    imp::physical_cores()
+}
+
+/// Logical CPUs the process may run on, in ascending order, if known. Under
+/// a cpuset or `taskset` these need not be `0..n`.
+pub fn allowed_cpus() -> Option<Vec<usize>> {
+    imp::allowed_cpus()
+}
+
+/// Logical CPU the calling thread is running on, if the platform says.
+pub fn current_cpu() -> Option<usize> {
+    imp::current_cpu()
+}
+
+/// Pins the calling thread to logical CPU `cpu`; returns false if unsupported
+/// or the kernel refused.
+pub fn pin_current_thread(cpu: usize) -> bool {
+    imp::pin_current_thread(cpu)
+}
+
+#[cfg(target_os = "linux")]
+mod imp {
+    use std::collections::HashSet;
+
+    /// `cpu_set_t` from <sched.h>: a 1024-bit mask.
+    #[repr(C)]
+    struct CpuSet {
+        bits: [u64; 16],
+    }
+
+    extern "C" {
+        fn sched_getaffinity(pid: i32, size: usize, mask: *mut CpuSet) -> i32;
+        fn sched_setaffinity(pid: i32, size: usize, mask: *const CpuSet) -> i32;
+        fn sched_getcpu() -> i32;
+    }
+
+    pub fn physical_cores() -> Option<usize> {
+        let mut cores = HashSet::new();
+        for entry in std::fs::read_dir("/sys/devices/system/cpu").ok()?.flatten() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            let Some(id) = name.strip_prefix("cpu") else {
+                continue;
+            };
+            if id.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+                continue;
+            }
+            // Siblings of one core share the same list, e.g. "0,8" or "0-1".
+            // Offline CPUs have no topology directory.
+            let siblings = entry.path().join("topology/thread_siblings_list");
+            let Ok(siblings) = std::fs::read_to_string(siblings) else {
+                continue;
+            };
+            cores.insert(siblings.trim().to_string());
+        }
+        (!cores.is_empty()).then_some(cores.len())
+    }
+
+    pub fn allowed_cpus() -> Option<Vec<usize>> {
+        let mut set = CpuSet { bits: [0; 16] };
+        // pid 0 is the calling thread
+        if unsafe { sched_getaffinity(0, std::mem::size_of::<CpuSet>(), &mut set) } != 0 {
+            return None;
+        }
+        let cpus: Vec<usize> = (0..set.bits.len() * 64)
+            .filter(|&cpu| set.bits[cpu / 64] & (1 << (cpu % 64)) != 0)
+            .collect();
+        (!cpus.is_empty()).then_some(cpus)
+    }
+
+    pub fn current_cpu() -> Option<usize> {
+        let cpu = unsafe { sched_getcpu() };
+        (cpu >= 0).then_some(cpu as usize)
+    }
+
+    pub fn pin_current_thread(cpu: usize) -> bool {
+        let mut set = CpuSet { bits: [0; 16] };
+        if cpu >= set.bits.len() * 64 {
+            return false;
+        }
+        set.bits[cpu / 64] |= 1 << (cpu % 64);
+        // pid 0 is the calling thread
+        unsafe { sched_setaffinity(0, std::mem::size_of::<CpuSet>(), &set) == 0 }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod imp {
+    pub fn physical_cores() -> Option<usize> {
+        None
+    }
+
+    pub fn allowed_cpus() -> Option<Vec<usize>> {
+        None
+    }
+
+    pub fn current_cpu() -> Option<usize> {
+        None
+    }
+
+    pub fn pin_current_thread(_cpu: usize) -> bool {
+        false
+    }
+}
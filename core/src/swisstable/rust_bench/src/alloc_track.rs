@@ -0,0 +1,89 @@
+//! Allocation counting via a wrapping global allocator.
+//!
+//! Enabled with `cargo run --release --features track-alloc`. Without the
+//! feature the system allocator is used untouched and every snapshot is zero,
+//! so benchmarks can call into this module unconditionally.
+
+/// Cumulative allocator counters at a point in time.
+#[derive(Clone, Copy, Default)]
+pub struct AllocSnapshot {
+    /// alloc, alloc_zeroed and realloc calls.
+    pub allocations: usize,
+    /// Bytes requested across those calls (realloc counts its new size).
+    pub bytes: usize,
+    /// realloc calls alone.
+    pub reallocations: usize,
+}
+
+impl AllocSnapshot {
+    /// Counters accumulated between `earlier` and `self`.
+    pub fn since(self, earlier: AllocSnapshot) -> AllocSnapshot {
+        AllocSnapshot {
+            allocations: self.allocations - earlier.allocations,
+            bytes: self.bytes - earlier.bytes,
+            reallocations: self.reallocations - earlier.reallocations,
+        }
+    }
+}
+
+/// Returns true when the counting allocator is compiled in.
+pub fn enabled() -> bool {
+    cfg!(feature = "track-alloc")
+}
+
+#[cfg(feature = "track-alloc")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    pub static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    pub static BYTES: AtomicUsize = AtomicUsize::new(0);
+    pub static REALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    struct CountingAlloc;
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(new_size, Ordering::Relaxed);
+            REALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAlloc = CountingAlloc;
+}
+
+/// Reads the current counters (all zero when tracking is disabled).
+pub fn snapshot() -> AllocSnapshot {
+    #[cfg(feature = "track-alloc")]
+    {
+        use std::sync::atomic::Ordering;
+        AllocSnapshot {
+            allocations: counting::ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: counting::BYTES.load(Ordering::Relaxed),
+            reallocations: counting::REALLOCATIONS.load(Ordering::Relaxed),
+        }
+    }
+    #[cfg(not(feature = "track-alloc"))]
+    {
+        AllocSnapshot::default()
+    }
+}
@@ -0,0 +1,216 @@
+//! Benchmark key generation.
+//!
+//! `KeyDistribution` is selected with `--distribution` and drives the key
+//! streams of the random-insert and random-lookup benchmarks. Every generator
+//! is deterministic for a given seed so hb and std always see the same keys.
+
+use crate::Rng;
+use std::fmt;
+use std::str::FromStr;
+
+/// Multiplier used to scatter Zipf ranks across the key domain (bijective on u64).
+const SCATTER: u64 = 0x9E37_79B9_7F4A_7C15;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyDistribution {
+    /// 0, 1, 2, ... (wrapping at the domain).
+    Sequential,
+    /// Independent uniform draws over the domain.
+    UniformRandom,
+    /// Ranks drawn with Zipf(theta) weights, scattered over the domain.
+    Zipf { theta: f64 },
+    /// `runs` bursts of consecutive keys, each starting at a random base.
+    Clustered { runs: usize },
+    /// A random permutation of 0..count.
+    DenseShuffled,
+}
+
+impl KeyDistribution {
+    /// Generates `count` keys in `0..domain` from `seed`.
+    pub fn generate(self, count: usize, domain: u64, seed: u64) -> Vec<i64> {
+        let mut rng = Rng::new(seed);
+        match self {
+            KeyDistribution::Sequential => (0..count as u64).map(|i| (i % domain) as i64).collect(),
+            KeyDistribution::UniformRandom => (0..count)
+                .map(|_| rng.next_bounded(domain) as i64)
+                .collect(),
+            KeyDistribution::Zipf { theta } => {
+                let ranks = (count as u64).min(domain).max(1) as usize;
+                zipf_ranks(&mut rng, ranks, count, theta)
+                    .into_iter()
+                    .map(|r| ((r as u64).wrapping_mul(SCATTER) % domain) as i64)
+                    .collect()
+            }
+            KeyDistribution::Clustered { runs } => {
+                let run_len = count.div_ceil(runs.max(1)).max(1);
+                let mut keys = Vec::with_capacity(count);
+                while keys.len() < count {
+                    let base = rng.next_bounded(domain);
+                    let len = run_len.min(count - keys.len());
+                    keys.extend((0..len as u64).map(|j| (base.wrapping_add(j) % domain) as i64));
+                }
+                keys
+            }
+            KeyDistribution::DenseShuffled => {
+                let mut keys: Vec<i64> = (0..count as u64).map(|i| (i % domain) as i64).collect();
+                // Fisher-Yates
+                for i in (1..keys.len()).rev() {
+                    let j = rng.next_bounded(i as u64 + 1) as usize;
+                    keys.swap(i, j);
+                }
+                keys
+            }
+        }
+    }
+}
+
+impl fmt::Display for KeyDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyDistribution::Sequential => write!(f, "sequential"),
+            KeyDistribution::UniformRandom => write!(f, "uniform"),
+            KeyDistribution::Zipf { theta } => write!(f, "zipf:{}", theta),
+            KeyDistribution::Clustered { runs } => write!(f, "clustered:{}", runs),
+            KeyDistribution::DenseShuffled => write!(f, "dense-shuffled"),
+        }
+    }
+}
+
+impl FromStr for KeyDistribution {
+    type Err = String;
+
+    /// Parses `sequential`, `uniform`, `zipf[:theta]`, `clustered[:runs]`, or `dense-shuffled`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, param) = match s.split_once(':') {
+            Some((name, param)) => (name, Some(param)),
+            None => (s, None),
+        };
+        let bad_param = || format!("invalid parameter in distribution '{}'", s);
+        match (name, param) {
+            ("sequential", None) => Ok(KeyDistribution::Sequential),
+            ("uniform", None) => Ok(KeyDistribution::UniformRandom),
+            ("zipf", None) => Ok(KeyDistribution::Zipf { theta: 1.0 }),
+            ("zipf", Some(p)) => match p.parse::<f64>() {
+                Ok(theta) if theta > 0.0 => Ok(KeyDistribution::Zipf { theta }),
+                _ => Err(bad_param()),
+            },
+            ("clustered", None) => Ok(KeyDistribution::Clustered { runs: 64 }),
+            ("clustered", Some(p)) => match p.parse::<usize>() {
+                Ok(runs) if runs > 0 => Ok(KeyDistribution::Clustered { runs }),
+                _ => Err(bad_param()),
+            },
+            ("dense-shuffled", None) => Ok(KeyDistribution::DenseShuffled),
+            _ => Err(format!("unknown distribution '{}'", s)),
+        }
+    }
+}
+
+/// Samples `count` ranks in `0..m` with Zipf(theta) weights via inverse CDF.
+pub fn zipf_ranks(rng: &mut Rng, m: usize, count: usize, theta: f64) -> Vec<usize> {
+    let mut cdf: Vec<f64> = Vec::with_capacity(m);
+    let mut total = 0.0;
+    for r in 0..m {
+        total += 1.0 / ((r + 1) as f64).powf(theta);
+        cdf.push(total);
+    }
+    (0..count)
+        .map(|_| {
+            let u = (rng.next() >> 11) as f64 / (1u64 << 53) as f64 * total;
+            cdf.partition_point(|&c| c < u).min(m - 1)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const SAMPLE: usize = 10_000;
+
+    #[test]
+    fn sequential_counts_up() {
+        let keys = KeyDistribution::Sequential.generate(SAMPLE, u64::MAX, 1);
+        assert!(keys.iter().enumerate().all(|(i, &k)| k == i as i64));
+    }
+
+    #[test]
+    fn uniform_covers_domain_evenly() {
+        let domain = 1_000u64;
+        let keys = KeyDistribution::UniformRandom.generate(SAMPLE, domain, 1);
+        assert!(keys.iter().all(|&k| (0..domain as i64).contains(&k)));
+        let mean = keys.iter().sum::<i64>() as f64 / SAMPLE as f64;
+        assert!((mean - 499.5).abs() < 15.0, "mean {}", mean);
+        // Each decile should hold roughly 10% of the draws
+        let mut deciles = [0usize; 10];
+        for &k in &keys {
+            deciles[k as usize / 100] += 1;
+        }
+        assert!(
+            deciles.iter().all(|&c| (800..1200).contains(&c)),
+            "{:?}",
+            deciles
+        );
+    }
+
+    #[test]
+    fn zipf_is_head_heavy() {
+        let keys = KeyDistribution::Zipf { theta: 1.0 }.generate(SAMPLE, u64::MAX, 1);
+        let mut counts: HashMap<i64, usize> = HashMap::new();
+        for &k in &keys {
+            *counts.entry(k).or_insert(0) += 1;
+        }
+        let mut freq: Vec<usize> = counts.into_values().collect();
+        freq.sort_unstable_by(|a, b| b.cmp(a));
+        // With theta = 1 over 10K ranks the top key gets 1/H(10K) ~= 10%
+        assert!((800..1300).contains(&freq[0]), "top {}", freq[0]);
+        // ...and the second roughly half as much
+        assert!(
+            freq[1] * 3 > freq[0] && freq[1] * 3 < freq[0] * 2,
+            "{:?}",
+            &freq[..2]
+        );
+        assert!(freq.len() < SAMPLE / 2);
+    }
+
+    #[test]
+    fn clustered_emits_consecutive_runs() {
+        let runs = 20;
+        let keys = KeyDistribution::Clustered { runs }.generate(SAMPLE, u64::MAX, 1);
+        let breaks = keys
+            .windows(2)
+            .filter(|w| w[1] != w[0].wrapping_add(1))
+            .count();
+        assert_eq!(breaks, runs - 1);
+    }
+
+    #[test]
+    fn dense_shuffled_is_a_permutation() {
+        let keys = KeyDistribution::DenseShuffled.generate(SAMPLE, u64::MAX, 1);
+        let mut sorted = keys.clone();
+        sorted.sort_unstable();
+        assert!(sorted.iter().enumerate().all(|(i, &k)| k == i as i64));
+        let in_place = keys
+            .iter()
+            .enumerate()
+            .filter(|&(i, &k)| k == i as i64)
+            .count();
+        assert!(in_place < 10, "{} keys left in place", in_place);
+    }
+
+    #[test]
+    fn parses_all_names() {
+        for name in [
+            "sequential",
+            "uniform",
+            "zipf:0.99",
+            "clustered:8",
+            "dense-shuffled",
+        ] {
+            let dist: KeyDistribution = name.parse().unwrap();
+            assert_eq!(dist.to_string(), name);
+        }
+        assert!("zipf:-1".parse::<KeyDistribution>().is_err());
+        assert!("gaussian".parse::<KeyDistribution>().is_err());
+    }
+}
@@ -7,12 +7,26 @@
 //!
 //! Run with: cargo run --release
 
-use hashbrown::{HashMap, HashSet};
+mod alloc_track;
+mod keygen;
+mod mem_probe;
+mod prefetch;
+
+use hashbrown::{DefaultHashBuilder, HashMap, HashSet};
+use keygen::KeyDistribution;
+use rustc_hash::FxBuildHasher;
+use std::collections::hash_map::RandomState;
 use std::collections::HashMap as StdHashMap;
 use std::collections::HashSet as StdHashSet;
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash, Hasher};
 use std::hint::black_box;
+use std::sync::OnceLock;
 use std::time::Instant;
 
+/// std's table with hashbrown's default hasher, for `--hasher-split`.
+type StdFastMap<K, V> = StdHashMap<K, V, DefaultHashBuilder>;
+
 // ============================================================================
 // Configuration
 // ============================================================================
@@ -20,7 +34,114 @@ use std::time::Instant;
 const ITERATIONS: usize = 5;
 const WARMUP_ITERATIONS: usize = 2;
 
-const SIZES: [usize; 4] = [1_000, 10_000, 100_000, 1_000_000];
+const SIZES: [usize; 5] = [1_000, 10_000, 100_000, 1_000_000, 10_000_000];
+
+/// Extra size enabled by `--huge`; most benchmarks need several GB here.
+const HUGE_SIZE: usize = 100_000_000;
+
+/// Default `--max-memory-gb` budget.
+const DEFAULT_MAX_MEMORY_GB: f64 = 8.0;
+
+/// Number of independently seeded maps built by `--seed-variance`.
+const SEED_TRIALS: usize = 20;
+
+/// Size at which `--experimental` runs the batched-prefetch lookup benchmark.
+const PREFETCH_SIZE: usize = 1_000_000;
+
+// ============================================================================
+// Command-line options
+// ============================================================================
+
+const USAGE: &str = "Usage: bench_swiss [--seed-variance] [--hash-breakdown] [--verify] \
+                     [--huge] [--max-memory-gb <GB>] [--distribution <DIST>] [--experimental] \
+                     [--hasher-split] [--capacity-report]
+       DIST: sequential | uniform | zipf[:theta] | clustered[:runs] | dense-shuffled";
+
+struct Options {
+    /// Re-run lookup-hit across many freshly seeded maps to expose hasher seed luck.
+    seed_variance: bool,
+    /// Annotate lookup results with the calibrated per-key hashing cost.
+    hash_breakdown: bool,
+    /// Check that hashbrown and std end every benchmark with identical contents.
+    verify: bool,
+    /// Append `HUGE_SIZE` to the size sweep.
+    huge: bool,
+    /// Benchmarks whose estimated peak allocation exceeds this are skipped.
+    max_memory_gb: f64,
+    /// Key stream used by the random insert and lookup benchmarks.
+    distribution: KeyDistribution,
+    /// Run benchmarks that depend on hashbrown internals (batched prefetch).
+    experimental: bool,
+    /// Also time std HashMap with hashbrown's hasher in insert/lookup/remove.
+    hasher_split: bool,
+    /// Print the observed capacity growth sequence instead of benchmarking.
+    capacity_report: bool,
+}
+
+impl Options {
+    fn from_args() -> Self {
+        let mut opts = Options {
+            seed_variance: false,
+            hash_breakdown: false,
+            verify: false,
+            huge: false,
+            max_memory_gb: DEFAULT_MAX_MEMORY_GB,
+            distribution: KeyDistribution::UniformRandom,
+            experimental: false,
+            hasher_split: false,
+            capacity_report: false,
+        };
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--seed-variance" => opts.seed_variance = true,
+                "--hash-breakdown" => opts.hash_breakdown = true,
+                "--verify" => opts.verify = true,
+                "--huge" => opts.huge = true,
+                "--experimental" => opts.experimental = true,
+                "--hasher-split" => opts.hasher_split = true,
+                "--capacity-report" => opts.capacity_report = true,
+                "--max-memory-gb" => {
+                    opts.max_memory_gb = match args.next().and_then(|v| v.parse().ok()) {
+                        Some(gb) if gb > 0.0 => gb,
+                        _ => usage_error("--max-memory-gb expects a positive number"),
+                    }
+                }
+                "--distribution" => {
+                    opts.distribution = match args.next().map(|v| v.parse()) {
+                        Some(Ok(dist)) => dist,
+                        Some(Err(e)) => usage_error(&e),
+                        None => usage_error("--distribution expects a value"),
+                    }
+                }
+                other => usage_error(&format!("Unknown option: {}", other)),
+            }
+        }
+
+        opts
+    }
+}
+
+fn usage_error(msg: &str) -> ! {
+    eprintln!("{}", msg);
+    eprintln!("{}", USAGE);
+    std::process::exit(2);
+}
+
+static OPTIONS: OnceLock<Options> = OnceLock::new();
+
+fn options() -> &'static Options {
+    OPTIONS.get_or_init(Options::from_args)
+}
+
+fn verify() -> bool {
+    options().verify
+}
+
+fn hasher_split() -> bool {
+    options().hasher_split
+}
 
 // ============================================================================
 // Statistics helpers
@@ -90,11 +211,79 @@ impl Rng {
     }
 }
 
+// ============================================================================
+// Verification (--verify)
+// ============================================================================
+
+/// Number of elements sampled in each direction when comparing sets.
+const VERIFY_SAMPLES: usize = 1_000;
+
+/// Order-independent summary of a map's contents.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+struct MapDigest {
+    len: usize,
+    xor: u64,
+}
+
+/// XORs a fixed-key hash of every (key, value) pair, so iteration order is irrelevant.
+fn map_digest<I, K, V>(entries: I) -> MapDigest
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Hash,
+    V: Hash,
+{
+    let mut digest = MapDigest::default();
+    for (k, v) in entries {
+        // DefaultHasher::new() uses fixed keys, unlike RandomState
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        k.hash(&mut hasher);
+        v.hash(&mut hasher);
+        digest.len += 1;
+        digest.xor ^= hasher.finish();
+    }
+    digest
+}
+
+/// Fails the run if the two map digests differ.
+fn verify_maps_equal(name: &str, hb: MapDigest, std: MapDigest) {
+    if hb != std {
+        panic!("verify failed in {}: hb {:?} != std {:?}", name, hb, std);
+    }
+}
+
+/// Fails the run if the scalar results of the two implementations differ.
+fn verify_results_equal<T: PartialEq + Debug>(name: &str, hb: T, std: T) {
+    if hb != std {
+        panic!("verify failed in {}: hb {:?} != std {:?}", name, hb, std);
+    }
+}
+
+/// Fails the run if the sets differ in length or in sampled membership.
+fn verify_sets_equal<T: Hash + Eq + Debug>(name: &str, hb: &HashSet<T>, std: &StdHashSet<T>) {
+    if hb.len() != std.len() {
+        panic!(
+            "verify failed in {}: hb len {} != std len {}",
+            name,
+            hb.len(),
+            std.len()
+        );
+    }
+    let step = (hb.len() / VERIFY_SAMPLES).max(1);
+    if let Some(x) = hb.iter().step_by(step).find(|x| !std.contains(*x)) {
+        panic!("verify failed in {}: {:?} in hb but not in std", name, x);
+    }
+    if let Some(x) = std.iter().step_by(step).find(|x| !hb.contains(*x)) {
+        panic!("verify failed in {}: {:?} in std but not in hb", name, x);
+    }
+}
+
 // ============================================================================
 // Main
 // ============================================================================
 
 fn main() {
+    let opts = options();
+
     println!();
     println!("{}", "=".repeat(80));
     println!("                    Rust hashbrown Benchmark Suite");
@@ -105,37 +294,121 @@ fn main() {
         WARMUP_ITERATIONS,
         std::env::consts::ARCH
     );
+    if alloc_track::enabled() {
+        println!("Allocation tracking: enabled");
+    }
+    if opts.verify {
+        println!("Verification: enabled (hb and std contents compared after each benchmark)");
+    }
+    println!(
+        "Key distribution: {} (Insert (rnd), Lookup (rnd), Bounded (LRU))",
+        opts.distribution
+    );
+    println!(
+        "Memory budget: {:.1} GB (--max-memory-gb)",
+        opts.max_memory_gb
+    );
+    if opts.seed_variance {
+        println!(
+            "Seed variance: {} freshly seeded maps per size",
+            SEED_TRIALS
+        );
+    }
+    if opts.hasher_split {
+        println!(
+            "Hasher split: hb | std + hashbrown hasher | std SipHash \
+             (ratios: layout, then hasher)"
+        );
+    }
+    if opts.experimental {
+        println!(
+            "Experimental: batched prefetch lookups at N = {}",
+            PREFETCH_SIZE
+        );
+    }
     println!();
 
-    for &n in &SIZES {
+    let huge = opts.huge.then_some(HUGE_SIZE);
+    if opts.capacity_report {
+        let sizes: Vec<usize> = SIZES.into_iter().chain(huge).collect();
+        capacity_report(&sizes);
+        println!();
+        return;
+    }
+
+    let hash_cost = opts.hash_breakdown.then(bench_hash_cost);
+    let hash_cost = hash_cost.as_ref();
+
+    for n in SIZES.into_iter().chain(huge) {
         println!("{}", "=".repeat(80));
         println!("N = {:>12}", n);
         println!("{}", "=".repeat(80));
         println!();
 
         // Core operations
-        bench_insert_sequential(n);
-        bench_insert_random(n);
-        bench_insert_prealloc(n);
-        bench_insert_unique(n);
+        run_guarded_growing("Insert (seq)", n, || bench_insert_sequential(n));
+        run_guarded_growing("Insert (rnd)", n, || bench_insert_random(n));
+        run_guarded("Insert (pre)", n, 1, || bench_insert_prealloc(n));
+        run_guarded("InsertUnique", n, 1, || bench_insert_unique(n));
+        println!();
+
+        run_guarded("Insert paths", n, 4, || bench_insert_paths(n));
+        println!();
+
+        run_guarded("Lookup (hit)", n, 2, || bench_lookup_hit(n, hash_cost));
+        run_guarded("Lookup (miss)", n, 2, || bench_lookup_miss(n, hash_cost));
+        run_guarded("Lookup (rnd)", n, 3, || bench_lookup_random(n, hash_cost));
+        println!();
+
+        run_guarded("Load factor", n, 4, || bench_load_factor(n));
+        println!();
+
+        run_guarded("Key domains", n, 2, || bench_key_domains(n));
+        println!();
+
+        if opts.seed_variance {
+            run_guarded("Seed variance", n, 1, || bench_seed_variance(n));
+            println!();
+        }
+
+        run_guarded("Remove", n, 1, || bench_remove(n));
+        run_guarded("Remove+Reins", n, 1, || bench_remove_and_reinsert(n));
         println!();
 
-        bench_lookup_hit(n);
-        bench_lookup_miss(n);
-        bench_lookup_random(n);
+        // Fixed-size churn (key schedules are ~4 maps' worth on their own)
+        run_guarded("Bounded (LRU)", n, 6, || bench_bounded_map(n));
         println!();
 
-        bench_remove(n);
-        bench_remove_and_reinsert(n);
+        // Copy-on-write snapshots
+        run_guarded("Clone+mutate", n, 2, || bench_clone_mutate(n));
         println!();
 
         // Iteration
-        bench_iteration(n);
-        bench_keys_iteration(n);
+        run_guarded("Iterate", n, 2, || bench_iteration(n));
+        run_guarded("Keys iter", n, 2, || bench_keys_iteration(n));
+        run_guarded("Sorted export", n, 3, || bench_sorted_keys_export(n));
+        println!();
+
+        // Whole-table value transforms
+        run_guarded("Map values", n, 3, || bench_transform_values(n));
+        println!();
+
+        // Snapshot diffing
+        run_guarded("Key diff", n, 3, || bench_map_key_diff(n));
         println!();
 
         // Entry API
-        bench_entry_api(n);
+        run_guarded_growing("Entry API", n, || bench_entry_api(n));
+        run_guarded("Insert-absent", n, 4, || bench_insert_if_absent(n));
+        println!();
+
+        // String workloads
+        run_guarded("Intern", n, 2, || bench_string_interning(n));
+        run_guarded("Upsert", n, 2, || bench_string_upsert(n));
+        println!();
+
+        // Single-key floor
+        bench_single_key(n);
         println!();
 
         // Set operations (for smaller sizes only)
@@ -144,11 +417,31 @@ fn main() {
             bench_set_contains(n);
             bench_set_union(n);
             bench_set_intersection(n);
+            bench_set_intersection_count(n);
+            bench_set_operators(n);
+            println!();
+        }
+
+        // Dedup: hash set vs sort
+        run_guarded("Dedup", n, 2, || bench_vec_dedup(n));
+        println!();
+
+        // Inline vs boxed 128-byte values: two kept maps plus one being built,
+        // each ~9x an <i64, i64> table
+        run_guarded("Boxed values", n, 27, || bench_boxed_values(n));
+        println!();
+
+        if opts.experimental && n == PREFETCH_SIZE {
+            bench_batched_prefetch(n);
             println!();
         }
 
         // Memory usage
         bench_memory_usage(n);
+        run_guarded("RSS delta", n, 2, || bench_memory_rss(n));
+        if alloc_track::enabled() {
+            run_guarded("Alloc churn", n, 2, || bench_alloc_churn(n));
+        }
         println!();
     }
 
@@ -158,6 +451,81 @@ fn main() {
     println!();
 }
 
+// ============================================================================
+// Hash Function Cost
+// ============================================================================
+
+/// Calibrated ns per `i64` hash for the hashers behind hb and std maps.
+struct HashCost {
+    hb_ns: f64,
+    std_ns: f64,
+}
+
+/// Average ns per `hash_one` over `keys`, without touching any table.
+fn time_hasher<S: BuildHasher, K: Hash>(build: &S, keys: &[K]) -> f64 {
+    let mut stats = Stats::new();
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        let mut acc: u64 = 0;
+        let start = Instant::now();
+        for k in keys {
+            acc ^= build.hash_one(k);
+        }
+        let elapsed = start.elapsed().as_nanos();
+        black_box(acc);
+        if iter >= WARMUP_ITERATIONS {
+            stats.add(elapsed);
+        }
+    }
+    stats.ns_per_op(keys.len())
+}
+
+/// Hashes the key stream with each hasher and prints ns per hash.
+fn bench_hash_cost() -> HashCost {
+    const KEYS: usize = 100_000;
+
+    let mut rng = Rng::new(13579);
+    let int_keys: Vec<i64> = (0..KEYS).map(|_| rng.next() as i64).collect();
+    let str_keys: Vec<String> = (0..KEYS)
+        .map(|_| format!("{:016x}{:016x}", rng.next(), rng.next()))
+        .collect();
+
+    let hb_build = DefaultHashBuilder::default();
+    let std_build = RandomState::new();
+    let fx_build = FxBuildHasher;
+
+    let cost = HashCost {
+        hb_ns: time_hasher(&hb_build, &int_keys),
+        std_ns: time_hasher(&std_build, &int_keys),
+    };
+
+    println!("=== Hash Function Cost ===");
+    println!(
+        "  {:<22} {:>13}   {:>13}",
+        "Hasher", "i64 key", "32B string"
+    );
+    println!(
+        "  {:<22} {:>8.2}ns/op   {:>8.2}ns/op",
+        "hashbrown default",
+        cost.hb_ns,
+        time_hasher(&hb_build, &str_keys)
+    );
+    println!(
+        "  {:<22} {:>8.2}ns/op   {:>8.2}ns/op",
+        "SipHash (std)",
+        cost.std_ns,
+        time_hasher(&std_build, &str_keys)
+    );
+    println!(
+        "  {:<22} {:>8.2}ns/op   {:>8.2}ns/op",
+        "FxHasher",
+        time_hasher(&fx_build, &int_keys),
+        time_hasher(&fx_build, &str_keys)
+    );
+    println!();
+
+    cost
+}
+
 // ============================================================================
 // Insert Benchmarks
 // ============================================================================
@@ -165,6 +533,10 @@ fn main() {
 fn bench_insert_sequential(n: usize) {
     let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_digest = MapDigest::default();
+    let mut std_digest = MapDigest::default();
+    let mut fast_stats = Stats::new();
+    let mut fast_digest = MapDigest::default();
 
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
         // hashbrown
@@ -179,6 +551,9 @@ fn bench_insert_sequential(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
+            if verify() {
+                hb_digest = map_digest(&map);
+            }
         }
 
         // std HashMap
@@ -193,19 +568,54 @@ fn bench_insert_sequential(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
+            if verify() {
+                std_digest = map_digest(&map);
+            }
+        }
+
+        // std HashMap + hashbrown's hasher (--hasher-split)
+        if hasher_split() {
+            let mut map: StdFastMap<i64, i64> = StdFastMap::default();
+            let start = Instant::now();
+            for i in 0..n {
+                map.insert(i as i64, i as i64);
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                fast_stats.add(elapsed);
+            }
+            if verify() {
+                fast_digest = map_digest(&map);
+            }
+        }
+    }
+
+    if verify() {
+        verify_maps_equal("Insert (seq)", hb_digest, std_digest);
+        if hasher_split() {
+            verify_maps_equal("Insert (seq)", hb_digest, fast_digest);
         }
     }
 
-    print_result("Insert (seq)", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    print_result_split(
+        "Insert (seq)",
+        hb_stats.ns_per_op(n),
+        hasher_split().then(|| fast_stats.ns_per_op(n)),
+        std_stats.ns_per_op(n),
+    );
 }
 
 fn bench_insert_random(n: usize) {
     let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_digest = MapDigest::default();
+    let mut std_digest = MapDigest::default();
+    let mut fast_stats = Stats::new();
+    let mut fast_digest = MapDigest::default();
 
-    // Pre-generate random keys
-    let mut rng = Rng::new(12345);
-    let keys: Vec<i64> = (0..n).map(|_| rng.next() as i64).collect();
+    // Pre-generate keys over the full u64 domain
+    let keys = options().distribution.generate(n, u64::MAX, 12345);
 
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
         // hashbrown
@@ -220,6 +630,9 @@ fn bench_insert_random(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
+            if verify() {
+                hb_digest = map_digest(&map);
+            }
         }
 
         // std HashMap
@@ -234,15 +647,51 @@ fn bench_insert_random(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
+            if verify() {
+                std_digest = map_digest(&map);
+            }
+        }
+
+        // std HashMap + hashbrown's hasher (--hasher-split)
+        if hasher_split() {
+            let mut map: StdFastMap<i64, i64> = StdFastMap::default();
+            let start = Instant::now();
+            for &k in &keys {
+                map.insert(k, k);
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                fast_stats.add(elapsed);
+            }
+            if verify() {
+                fast_digest = map_digest(&map);
+            }
+        }
+    }
+
+    if verify() {
+        verify_maps_equal("Insert (rnd)", hb_digest, std_digest);
+        if hasher_split() {
+            verify_maps_equal("Insert (rnd)", hb_digest, fast_digest);
         }
     }
 
-    print_result("Insert (rnd)", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    print_result_split(
+        "Insert (rnd)",
+        hb_stats.ns_per_op(n),
+        hasher_split().then(|| fast_stats.ns_per_op(n)),
+        std_stats.ns_per_op(n),
+    );
 }
 
 fn bench_insert_prealloc(n: usize) {
     let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_digest = MapDigest::default();
+    let mut std_digest = MapDigest::default();
+    let mut fast_stats = Stats::new();
+    let mut fast_digest = MapDigest::default();
 
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
         // hashbrown with capacity
@@ -257,6 +706,9 @@ fn bench_insert_prealloc(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
+            if verify() {
+                hb_digest = map_digest(&map);
+            }
         }
 
         // std HashMap with capacity
@@ -271,15 +723,50 @@ fn bench_insert_prealloc(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
+            if verify() {
+                std_digest = map_digest(&map);
+            }
+        }
+
+        // std HashMap + hashbrown's hasher (--hasher-split)
+        if hasher_split() {
+            let mut map: StdFastMap<i64, i64> =
+                StdFastMap::with_capacity_and_hasher(n, DefaultHashBuilder::default());
+            let start = Instant::now();
+            for i in 0..n {
+                map.insert(i as i64, i as i64);
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                fast_stats.add(elapsed);
+            }
+            if verify() {
+                fast_digest = map_digest(&map);
+            }
         }
     }
 
-    print_result("Insert (pre)", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    if verify() {
+        verify_maps_equal("Insert (pre)", hb_digest, std_digest);
+        if hasher_split() {
+            verify_maps_equal("Insert (pre)", hb_digest, fast_digest);
+        }
+    }
+
+    print_result_split(
+        "Insert (pre)",
+        hb_stats.ns_per_op(n),
+        hasher_split().then(|| fast_stats.ns_per_op(n)),
+        std_stats.ns_per_op(n),
+    );
 }
 
 fn bench_insert_unique(n: usize) {
     let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_digest = MapDigest::default();
+    let mut std_digest = MapDigest::default();
 
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
         // hashbrown with insert_unique_unchecked (fastest path)
@@ -296,6 +783,9 @@ fn bench_insert_unique(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
+            if verify() {
+                hb_digest = map_digest(&map);
+            }
         }
 
         // std HashMap (no equivalent)
@@ -310,19 +800,125 @@ fn bench_insert_unique(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
+            if verify() {
+                std_digest = map_digest(&map);
+            }
         }
     }
 
+    if verify() {
+        verify_maps_equal("InsertUnique", hb_digest, std_digest);
+    }
+
     print_result("InsertUnique", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
 }
 
+/// Builds the same map of n unique random keys with each insert idiom.
+///
+/// Every variant starts from `with_capacity(n)` so the idiom is the only thing
+/// that differs. The resulting hashbrown maps are asserted equal.
+fn bench_insert_paths(n: usize) {
+    let mut insert_stats = Stats::new();
+    let mut entry_stats = Stats::new();
+    let mut unique_stats = Stats::new();
+    let mut std_stats = Stats::new();
+    let mut std_digest = MapDigest::default();
+
+    // xorshift64 never repeats within its period, so these keys are unique
+    let mut rng = Rng::new(11235);
+    let keys: Vec<i64> = (0..n).map(|_| rng.next() as i64).collect();
+
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // hashbrown insert
+        let mut insert_map: HashMap<i64, i64> = HashMap::with_capacity(n);
+        let start = Instant::now();
+        for &k in &keys {
+            insert_map.insert(k, k);
+        }
+        let elapsed = start.elapsed().as_nanos();
+        black_box(&insert_map);
+        if iter >= WARMUP_ITERATIONS {
+            insert_stats.add(elapsed);
+        }
+
+        // hashbrown entry().or_insert()
+        let mut entry_map: HashMap<i64, i64> = HashMap::with_capacity(n);
+        let start = Instant::now();
+        for &k in &keys {
+            entry_map.entry(k).or_insert(k);
+        }
+        let elapsed = start.elapsed().as_nanos();
+        black_box(&entry_map);
+        if iter >= WARMUP_ITERATIONS {
+            entry_stats.add(elapsed);
+        }
+
+        // hashbrown insert_unique_unchecked
+        let mut unique_map: HashMap<i64, i64> = HashMap::with_capacity(n);
+        let start = Instant::now();
+        for &k in &keys {
+            unsafe {
+                unique_map.insert_unique_unchecked(k, k);
+            }
+        }
+        let elapsed = start.elapsed().as_nanos();
+        black_box(&unique_map);
+        if iter >= WARMUP_ITERATIONS {
+            unique_stats.add(elapsed);
+        }
+
+        // std HashMap insert (reference)
+        {
+            let mut map: StdHashMap<i64, i64> = StdHashMap::with_capacity(n);
+            let start = Instant::now();
+            for &k in &keys {
+                map.insert(k, k);
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                std_stats.add(elapsed);
+            }
+            if verify() {
+                std_digest = map_digest(&map);
+            }
+        }
+
+        assert!(insert_map == entry_map && entry_map == unique_map);
+        if verify() {
+            verify_maps_equal("Insert paths", map_digest(&insert_map), std_digest);
+        }
+    }
+
+    let reference = std_stats.ns_per_op(n);
+    println!("  Insert paths (unique random keys, pre-reserved):");
+    for (name, stats) in [
+        ("hb insert", &insert_stats),
+        ("hb entry", &entry_stats),
+        ("hb insert_unique", &unique_stats),
+        ("std insert", &std_stats),
+    ] {
+        let ns = stats.ns_per_op(n);
+        println!(
+            "    {:<18} {:>8.1}ns/op   {:.2}x vs std insert",
+            name,
+            ns,
+            reference / ns
+        );
+    }
+}
+
 // ============================================================================
 // Lookup Benchmarks
 // ============================================================================
 
-fn bench_lookup_hit(n: usize) {
+fn bench_lookup_hit(n: usize, hash_cost: Option<&HashCost>) {
     let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_result = 0;
+    let mut std_result = 0;
+    let mut fast_stats = Stats::new();
+    let mut fast_result = 0;
 
     // Setup maps
     let mut hb_map: HashMap<i64, i64> = HashMap::with_capacity(n);
@@ -331,6 +927,11 @@ fn bench_lookup_hit(n: usize) {
         hb_map.insert(i as i64, i as i64);
         std_map.insert(i as i64, i as i64);
     }
+    let fast_map: StdFastMap<i64, i64> = if hasher_split() {
+        (0..n).map(|i| (i as i64, i as i64)).collect()
+    } else {
+        StdFastMap::default()
+    };
 
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
         // hashbrown
@@ -344,6 +945,7 @@ fn bench_lookup_hit(n: usize) {
             }
             let elapsed = start.elapsed().as_nanos();
             black_box(checksum);
+            hb_result = checksum;
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
@@ -360,18 +962,56 @@ fn bench_lookup_hit(n: usize) {
             }
             let elapsed = start.elapsed().as_nanos();
             black_box(checksum);
+            std_result = checksum;
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
         }
+
+        // std HashMap + hashbrown's hasher (--hasher-split)
+        if hasher_split() {
+            let mut checksum: i64 = 0;
+            let start = Instant::now();
+            for i in 0..n {
+                if let Some(&v) = fast_map.get(&(i as i64)) {
+                    checksum = checksum.wrapping_add(v);
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(checksum);
+            fast_result = checksum;
+            if iter >= WARMUP_ITERATIONS {
+                fast_stats.add(elapsed);
+            }
+        }
+    }
+
+    if verify() {
+        verify_results_equal("Lookup (hit)", hb_result, std_result);
+        if hasher_split() {
+            verify_results_equal("Lookup (hit)", hb_result, fast_result);
+        }
     }
 
-    print_result("Lookup (hit)", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    print_result_split(
+        "Lookup (hit)",
+        hb_stats.ns_per_op(n),
+        hasher_split().then(|| fast_stats.ns_per_op(n)),
+        std_stats.ns_per_op(n),
+    );
+    if let Some(cost) = hash_cost {
+        print_hash_share(cost, hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    }
 }
 
-fn bench_lookup_miss(n: usize) {
+#[allow(clippy::unnecessary_get_then_check)] // `get` is what the miss path has always timed
+fn bench_lookup_miss(n: usize, hash_cost: Option<&HashCost>) {
     let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_result = 0;
+    let mut std_result = 0;
+    let mut fast_stats = Stats::new();
+    let mut fast_result = 0;
 
     // Setup maps with keys 0..n
     let mut hb_map: HashMap<i64, i64> = HashMap::with_capacity(n);
@@ -380,6 +1020,11 @@ fn bench_lookup_miss(n: usize) {
         hb_map.insert(i as i64, i as i64);
         std_map.insert(i as i64, i as i64);
     }
+    let fast_map: StdFastMap<i64, i64> = if hasher_split() {
+        (0..n).map(|i| (i as i64, i as i64)).collect()
+    } else {
+        StdFastMap::default()
+    };
 
     // Lookup keys n..2n (all misses)
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
@@ -394,6 +1039,7 @@ fn bench_lookup_miss(n: usize) {
             }
             let elapsed = start.elapsed().as_nanos();
             black_box(miss_count);
+            hb_result = miss_count;
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
@@ -410,18 +1056,55 @@ fn bench_lookup_miss(n: usize) {
             }
             let elapsed = start.elapsed().as_nanos();
             black_box(miss_count);
+            std_result = miss_count;
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
         }
+
+        // std HashMap + hashbrown's hasher (--hasher-split)
+        if hasher_split() {
+            let mut miss_count: usize = 0;
+            let start = Instant::now();
+            for i in n..(n * 2) {
+                if fast_map.get(&(i as i64)).is_none() {
+                    miss_count += 1;
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(miss_count);
+            fast_result = miss_count;
+            if iter >= WARMUP_ITERATIONS {
+                fast_stats.add(elapsed);
+            }
+        }
     }
 
-    print_result("Lookup (miss)", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
-}
+    if verify() {
+        verify_results_equal("Lookup (miss)", hb_result, std_result);
+        if hasher_split() {
+            verify_results_equal("Lookup (miss)", hb_result, fast_result);
+        }
+    }
 
-fn bench_lookup_random(n: usize) {
-    let mut hb_stats = Stats::new();
+    print_result_split(
+        "Lookup (miss)",
+        hb_stats.ns_per_op(n),
+        hasher_split().then(|| fast_stats.ns_per_op(n)),
+        std_stats.ns_per_op(n),
+    );
+    if let Some(cost) = hash_cost {
+        print_hash_share(cost, hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    }
+}
+
+fn bench_lookup_random(n: usize, hash_cost: Option<&HashCost>) {
+    let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_result = 0;
+    let mut std_result = 0;
+    let mut fast_stats = Stats::new();
+    let mut fast_result = 0;
 
     // Setup maps
     let mut hb_map: HashMap<i64, i64> = HashMap::with_capacity(n);
@@ -430,12 +1113,14 @@ fn bench_lookup_random(n: usize) {
         hb_map.insert(i as i64, i as i64);
         std_map.insert(i as i64, i as i64);
     }
+    let fast_map: StdFastMap<i64, i64> = if hasher_split() {
+        (0..n).map(|i| (i as i64, i as i64)).collect()
+    } else {
+        StdFastMap::default()
+    };
 
-    // Pre-generate random lookup keys (50% hit, 50% miss)
-    let mut rng = Rng::new(54321);
-    let lookup_keys: Vec<i64> = (0..n)
-        .map(|_| rng.next_bounded((n * 2) as u64) as i64)
-        .collect();
+    // Pre-generate lookup keys over 0..2n (50% hit, 50% miss when uniform)
+    let lookup_keys = options().distribution.generate(n, (n * 2) as u64, 54321);
 
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
         // hashbrown
@@ -449,6 +1134,7 @@ fn bench_lookup_random(n: usize) {
             }
             let elapsed = start.elapsed().as_nanos();
             black_box(checksum);
+            hb_result = checksum;
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
@@ -465,13 +1151,359 @@ fn bench_lookup_random(n: usize) {
             }
             let elapsed = start.elapsed().as_nanos();
             black_box(checksum);
+            std_result = checksum;
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
         }
+
+        // std HashMap + hashbrown's hasher (--hasher-split)
+        if hasher_split() {
+            let mut checksum: i64 = 0;
+            let start = Instant::now();
+            for &k in &lookup_keys {
+                if let Some(&v) = fast_map.get(&k) {
+                    checksum = checksum.wrapping_add(v);
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(checksum);
+            fast_result = checksum;
+            if iter >= WARMUP_ITERATIONS {
+                fast_stats.add(elapsed);
+            }
+        }
+    }
+
+    if verify() {
+        verify_results_equal("Lookup (rnd)", hb_result, std_result);
+        if hasher_split() {
+            verify_results_equal("Lookup (rnd)", hb_result, fast_result);
+        }
+    }
+
+    print_result_split(
+        "Lookup (rnd)",
+        hb_stats.ns_per_op(n),
+        hasher_split().then(|| fast_stats.ns_per_op(n)),
+        std_stats.ns_per_op(n),
+    );
+    if let Some(cost) = hash_cost {
+        print_hash_share(cost, hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    }
+}
+
+/// Insert and lookup-hit over three key domains of n keys each.
+///
+/// `dense` is 0..n, `stride64` is 0..n times 64 (the low six bits are always
+/// zero, as with aligned pointers or page IDs), and `sparse` is n random
+/// u64s. Weak hash mixing shows up as a slowdown on `stride64` only. Maps
+/// are pre-reserved, and lookups visit the keys in one shared random order.
+fn bench_key_domains(n: usize) {
+    let mut rng = Rng::new(33550336);
+    let mut order: Vec<usize> = (0..n).collect();
+    // Fisher-Yates
+    for i in (1..n).rev() {
+        let j = rng.next_bounded(i as u64 + 1) as usize;
+        order.swap(i, j);
+    }
+
+    let domains: [(&str, &str, Vec<i64>); 3] = [
+        ("Dense ins", "Dense hit", (0..n as i64).collect()),
+        (
+            "Stride64 ins",
+            "Stride64 hit",
+            (0..n as i64).map(|i| i * 64).collect(),
+        ),
+        (
+            "Sparse ins",
+            "Sparse hit",
+            (0..n).map(|_| rng.next() as i64).collect(),
+        ),
+    ];
+
+    for (insert_name, hit_name, keys) in domains {
+        let lookups: Vec<i64> = order.iter().map(|&i| keys[i]).collect();
+
+        let mut hb_insert = Stats::new();
+        let mut std_insert = Stats::new();
+        let mut hb_hit = Stats::new();
+        let mut std_hit = Stats::new();
+        let mut hb_result = (MapDigest::default(), 0);
+        let mut std_result = (MapDigest::default(), 0);
+
+        for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+            // hashbrown
+            {
+                let mut map: HashMap<i64, i64> = HashMap::with_capacity(n);
+                let start = Instant::now();
+                for &k in &keys {
+                    map.insert(k, k);
+                }
+                let insert_elapsed = start.elapsed().as_nanos();
+                let mut checksum: i64 = 0;
+                let start = Instant::now();
+                for k in &lookups {
+                    if let Some(&v) = map.get(k) {
+                        checksum = checksum.wrapping_add(v);
+                    }
+                }
+                let hit_elapsed = start.elapsed().as_nanos();
+                black_box(checksum);
+                if iter >= WARMUP_ITERATIONS {
+                    hb_insert.add(insert_elapsed);
+                    hb_hit.add(hit_elapsed);
+                }
+                if verify() {
+                    hb_result = (map_digest(&map), checksum);
+                }
+            }
+
+            // std HashMap
+            {
+                let mut map: StdHashMap<i64, i64> = StdHashMap::with_capacity(n);
+                let start = Instant::now();
+                for &k in &keys {
+                    map.insert(k, k);
+                }
+                let insert_elapsed = start.elapsed().as_nanos();
+                let mut checksum: i64 = 0;
+                let start = Instant::now();
+                for k in &lookups {
+                    if let Some(&v) = map.get(k) {
+                        checksum = checksum.wrapping_add(v);
+                    }
+                }
+                let hit_elapsed = start.elapsed().as_nanos();
+                black_box(checksum);
+                if iter >= WARMUP_ITERATIONS {
+                    std_insert.add(insert_elapsed);
+                    std_hit.add(hit_elapsed);
+                }
+                if verify() {
+                    std_result = (map_digest(&map), checksum);
+                }
+            }
+        }
+
+        if verify() {
+            verify_maps_equal(insert_name, hb_result.0, std_result.0);
+            verify_results_equal(hit_name, hb_result.1, std_result.1);
+        }
+
+        print_result(insert_name, hb_insert.ns_per_op(n), std_insert.ns_per_op(n));
+        print_result(hit_name, hb_hit.ns_per_op(n), std_hit.ns_per_op(n));
+    }
+}
+
+/// Random lookup hit/miss cost at fixed occupancy levels of one table size.
+///
+/// Both maps reserve capacity for n entries up front; the bucket count is
+/// derived from the reported `capacity()` (7/8 of buckets) and each row fills
+/// the table to the given fraction of its buckets. The last row fills exactly
+/// to `capacity()`, the fullest the table gets before it resizes. Every row
+/// performs n lookups so the columns are comparable. Live keys are even and
+/// miss keys are odd.
+fn bench_load_factor(n: usize) {
+    const LOAD_FACTORS: [f64; 4] = [0.25, 0.50, 0.70, 0.85];
+
+    let hb_capacity = HashMap::<i64, i64>::with_capacity(n).capacity();
+    let std_capacity = StdHashMap::<i64, i64>::with_capacity(n).capacity();
+    let buckets = hb_capacity.next_power_of_two();
+    let max_fill = hb_capacity.min(std_capacity);
+
+    let mut levels: Vec<(String, usize)> = LOAD_FACTORS
+        .iter()
+        .map(|&lf| {
+            (
+                format!("{:.1}%", lf * 100.0),
+                (buckets as f64 * lf) as usize,
+            )
+        })
+        .filter(|&(_, fill)| fill > 0 && fill < max_fill)
+        .collect();
+    levels.push((
+        format!("max {:.1}%", max_fill as f64 / buckets as f64 * 100.0),
+        max_fill,
+    ));
+
+    println!(
+        "  Load factor    ({} buckets, {} lookups per row)",
+        buckets, n
+    );
+    println!(
+        "    {:<12} {:>9} {:>9} {:>9} {:>9}   ns/op",
+        "occupancy", "hit hb", "hit std", "miss hb", "miss std"
+    );
+
+    let mut rng = Rng::new(86420);
+    for (label, fill) in levels {
+        let keys: Vec<i64> = (0..fill).map(|_| (rng.next() & !1) as i64).collect();
+        let hits: Vec<i64> = (0..n)
+            .map(|_| keys[rng.next_bounded(fill as u64) as usize])
+            .collect();
+        let misses: Vec<i64> = (0..n).map(|_| (rng.next() | 1) as i64).collect();
+
+        let mut hb_map: HashMap<i64, i64> = HashMap::with_capacity(n);
+        let mut std_map: StdHashMap<i64, i64> = StdHashMap::with_capacity(n);
+        for &k in &keys {
+            hb_map.insert(k, k);
+            std_map.insert(k, k);
+        }
+        // Setup must not have grown the tables, or the row measures a bigger one
+        assert_eq!(hb_map.capacity(), hb_capacity, "hb grew during setup");
+        assert_eq!(std_map.capacity(), std_capacity, "std grew during setup");
+
+        let mut hb_hit = Stats::new();
+        let mut std_hit = Stats::new();
+        let mut hb_miss = Stats::new();
+        let mut std_miss = Stats::new();
+        let mut hb_result = (0, 0);
+        let mut std_result = (0, 0);
+
+        for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+            // hashbrown
+            {
+                let mut found: usize = 0;
+                let start = Instant::now();
+                for k in &hits {
+                    if hb_map.contains_key(k) {
+                        found += 1;
+                    }
+                }
+                let hit_elapsed = start.elapsed().as_nanos();
+                let start = Instant::now();
+                for k in &misses {
+                    if hb_map.contains_key(k) {
+                        found += 1;
+                    }
+                }
+                let miss_elapsed = start.elapsed().as_nanos();
+                black_box(found);
+                hb_result = (found, hb_map.len());
+                if iter >= WARMUP_ITERATIONS {
+                    hb_hit.add(hit_elapsed);
+                    hb_miss.add(miss_elapsed);
+                }
+            }
+
+            // std HashMap
+            {
+                let mut found: usize = 0;
+                let start = Instant::now();
+                for k in &hits {
+                    if std_map.contains_key(k) {
+                        found += 1;
+                    }
+                }
+                let hit_elapsed = start.elapsed().as_nanos();
+                let start = Instant::now();
+                for k in &misses {
+                    if std_map.contains_key(k) {
+                        found += 1;
+                    }
+                }
+                let miss_elapsed = start.elapsed().as_nanos();
+                black_box(found);
+                std_result = (found, std_map.len());
+                if iter >= WARMUP_ITERATIONS {
+                    std_hit.add(hit_elapsed);
+                    std_miss.add(miss_elapsed);
+                }
+            }
+        }
+
+        if verify() {
+            verify_results_equal("Load factor", hb_result, std_result);
+        }
+
+        println!(
+            "    {:<12} {:>9.1} {:>9.1} {:>9.1} {:>9.1}",
+            label,
+            hb_hit.ns_per_op(n),
+            std_hit.ns_per_op(n),
+            hb_miss.ns_per_op(n),
+            std_miss.ns_per_op(n)
+        );
+    }
+}
+
+// ============================================================================
+// Hasher Seed Variance
+// ============================================================================
+
+/// Measures lookup-hit on `SEED_TRIALS` maps, each built with a fresh hasher.
+///
+/// std's `RandomState` draws new SipHash keys per map and hashbrown's default
+/// hasher is seeded per instance as well, so a single run only shows one draw.
+/// Reporting min/median/max across seeds shows how much of the hb-vs-std gap
+/// survives the worst pairing of seeds.
+fn bench_seed_variance(n: usize) {
+    let mut hb_samples: Vec<f64> = Vec::with_capacity(SEED_TRIALS);
+    let mut std_samples: Vec<f64> = Vec::with_capacity(SEED_TRIALS);
+
+    for _ in 0..SEED_TRIALS {
+        let mut hb_result = 0;
+        let mut std_result = 0;
+
+        // hashbrown (fresh default hasher)
+        {
+            let mut map: HashMap<i64, i64> = HashMap::with_capacity(n);
+            for i in 0..n {
+                map.insert(i as i64, i as i64);
+            }
+
+            let mut stats = Stats::new();
+            for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+                let mut checksum: i64 = 0;
+                let start = Instant::now();
+                for i in 0..n {
+                    if let Some(&v) = map.get(&(i as i64)) {
+                        checksum = checksum.wrapping_add(v);
+                    }
+                }
+                let elapsed = start.elapsed().as_nanos();
+                black_box(checksum);
+                hb_result = checksum;
+                if iter >= WARMUP_ITERATIONS {
+                    stats.add(elapsed);
+                }
+            }
+            hb_samples.push(stats.ns_per_op(n));
+        }
+
+        // std HashMap (fresh RandomState)
+        {
+            let mut map: StdHashMap<i64, i64> = StdHashMap::with_capacity(n);
+            for i in 0..n {
+                map.insert(i as i64, i as i64);
+            }
+
+            let mut stats = Stats::new();
+            for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+                let mut checksum: i64 = 0;
+                let start = Instant::now();
+                for i in 0..n {
+                    if let Some(&v) = map.get(&(i as i64)) {
+                        checksum = checksum.wrapping_add(v);
+                    }
+                }
+                let elapsed = start.elapsed().as_nanos();
+                black_box(checksum);
+                std_result = checksum;
+                if iter >= WARMUP_ITERATIONS {
+                    stats.add(elapsed);
+                }
+            }
+            std_samples.push(stats.ns_per_op(n));
+        }
+
+        if verify() {
+            verify_results_equal("Seed variance", hb_result, std_result);
+        }
     }
 
-    print_result("Lookup (rnd)", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    print_spread("Lookup (hit)", &mut hb_samples, &mut std_samples);
 }
 
 // ============================================================================
@@ -481,6 +1513,10 @@ fn bench_lookup_random(n: usize) {
 fn bench_remove(n: usize) {
     let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_digest = MapDigest::default();
+    let mut std_digest = MapDigest::default();
+    let mut fast_stats = Stats::new();
+    let mut fast_digest = MapDigest::default();
 
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
         // hashbrown
@@ -498,6 +1534,9 @@ fn bench_remove(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
+            if verify() {
+                hb_digest = map_digest(&map);
+            }
         }
 
         // std HashMap
@@ -515,15 +1554,53 @@ fn bench_remove(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
+            if verify() {
+                std_digest = map_digest(&map);
+            }
+        }
+
+        // std HashMap + hashbrown's hasher (--hasher-split)
+        if hasher_split() {
+            let mut map: StdFastMap<i64, i64> =
+                StdFastMap::with_capacity_and_hasher(n, DefaultHashBuilder::default());
+            for i in 0..n {
+                map.insert(i as i64, i as i64);
+            }
+            let start = Instant::now();
+            for i in 0..n {
+                map.remove(&(i as i64));
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                fast_stats.add(elapsed);
+            }
+            if verify() {
+                fast_digest = map_digest(&map);
+            }
+        }
+    }
+
+    if verify() {
+        verify_maps_equal("Remove", hb_digest, std_digest);
+        if hasher_split() {
+            verify_maps_equal("Remove", hb_digest, fast_digest);
         }
     }
 
-    print_result("Remove", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    print_result_split(
+        "Remove",
+        hb_stats.ns_per_op(n),
+        hasher_split().then(|| fast_stats.ns_per_op(n)),
+        std_stats.ns_per_op(n),
+    );
 }
 
 fn bench_remove_and_reinsert(n: usize) {
     let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_digest = MapDigest::default();
+    let mut std_digest = MapDigest::default();
 
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
         // hashbrown: remove half, reinsert
@@ -546,6 +1623,9 @@ fn bench_remove_and_reinsert(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
+            if verify() {
+                hb_digest = map_digest(&map);
+            }
         }
 
         // std HashMap
@@ -566,12 +1646,237 @@ fn bench_remove_and_reinsert(n: usize) {
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
+            if verify() {
+                std_digest = map_digest(&map);
+            }
         }
     }
 
+    if verify() {
+        verify_maps_equal("Remove+Reins", hb_digest, std_digest);
+    }
+
     print_result("Remove+Reins", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
 }
 
+// ============================================================================
+// Bounded-Map Workload
+// ============================================================================
+
+/// Keeps a map at n entries while 4n fresh keys stream through.
+///
+/// Each step inserts a fresh key, evicts the oldest one, and performs a lookup
+/// that hits 80% of the time. The eviction order comes from a ring buffer that
+/// is simulated before timing, so the timed loop only contains map calls. Live
+/// keys are even and miss keys are odd, so misses never hit by accident.
+/// Inserted keys, miss keys and the ring slots that hits pick all follow
+/// `--distribution`; with a repeating distribution a fresh key may already
+/// be live, so the map can dip below n entries.
+fn bench_bounded_map(n: usize) {
+    let steps = n * 4;
+    let dist = options().distribution;
+    let domain = u64::MAX >> 2;
+
+    let mut rng = Rng::new(24680);
+    let stream: Vec<i64> = dist
+        .generate(n + steps, domain, 24680)
+        .into_iter()
+        .map(|k| k << 1)
+        .collect();
+    let (initial, fresh) = stream.split_at(n);
+    let hit_slots = dist.generate(steps, n as u64, 13579);
+    let misses = dist.generate(steps, domain, 97531);
+
+    // Simulate the ring buffer to get the eviction and lookup schedules
+    let mut ring = initial.to_vec();
+    let mut evictions: Vec<i64> = Vec::with_capacity(steps);
+    let mut lookups: Vec<i64> = Vec::with_capacity(steps);
+    for (s, &k) in fresh.iter().enumerate() {
+        let slot = s % n;
+        evictions.push(ring[slot]);
+        ring[slot] = k;
+        if rng.next_bounded(10) < 8 {
+            lookups.push(ring[hit_slots[s] as usize]);
+        } else {
+            lookups.push((misses[s] << 1) | 1);
+        }
+    }
+
+    let mut hb_stats = Stats::new();
+    let mut std_stats = Stats::new();
+    let mut hb_digest = MapDigest::default();
+    let mut std_digest = MapDigest::default();
+    let mut hb_result = 0;
+    let mut std_result = 0;
+
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // hashbrown
+        {
+            let mut map: HashMap<i64, i64> = HashMap::with_capacity(n);
+            for &k in initial {
+                map.insert(k, k);
+            }
+            let mut hits: usize = 0;
+            let start = Instant::now();
+            for s in 0..steps {
+                map.remove(&evictions[s]);
+                map.insert(fresh[s], fresh[s]);
+                if map.contains_key(&lookups[s]) {
+                    hits += 1;
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(hits);
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                hb_stats.add(elapsed);
+            }
+            hb_result = hits;
+            if verify() {
+                hb_digest = map_digest(&map);
+            }
+        }
+
+        // std HashMap
+        {
+            let mut map: StdHashMap<i64, i64> = StdHashMap::with_capacity(n);
+            for &k in initial {
+                map.insert(k, k);
+            }
+            let mut hits: usize = 0;
+            let start = Instant::now();
+            for s in 0..steps {
+                map.remove(&evictions[s]);
+                map.insert(fresh[s], fresh[s]);
+                if map.contains_key(&lookups[s]) {
+                    hits += 1;
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(hits);
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                std_stats.add(elapsed);
+            }
+            std_result = hits;
+            if verify() {
+                std_digest = map_digest(&map);
+            }
+        }
+    }
+
+    if verify() {
+        verify_results_equal("Bounded (LRU)", hb_result, std_result);
+        verify_maps_equal("Bounded (LRU)", hb_digest, std_digest);
+    }
+
+    // remove + insert + lookup per step
+    let ops = steps * 3;
+    print_throughput(
+        "Bounded (LRU)",
+        ops as f64 / (hb_stats.avg_ns() as f64 / 1e9),
+        ops as f64 / (std_stats.avg_ns() as f64 / 1e9),
+    );
+}
+
+// ============================================================================
+// Snapshot Benchmarks
+// ============================================================================
+
+/// Clones an n-entry map, then updates 1% of its entries and inserts 1% new keys.
+///
+/// A clone keeps the source's bucket count, so if the source has little slack
+/// the new keys force a resize straight after the copy. Whether `capacity()`
+/// changed during the mutation phase is reported on the result line. Times are
+/// per source entry and cover clone and mutation together.
+fn bench_clone_mutate(n: usize) {
+    let touched = (n / 100).max(1);
+    let mut rng = Rng::new(16180);
+    let updates: Vec<i64> = (0..touched)
+        .map(|_| rng.next_bounded(n as u64) as i64)
+        .collect();
+    let fresh: Vec<i64> = (0..touched).map(|i| (n + i) as i64).collect();
+
+    let hb_map: HashMap<i64, i64> = (0..n as i64).map(|i| (i, i)).collect();
+    let std_map: StdHashMap<i64, i64> = (0..n as i64).map(|i| (i, i)).collect();
+
+    let mut hb_stats = Stats::new();
+    let mut std_stats = Stats::new();
+    let mut hb_grew = false;
+    let mut std_grew = false;
+    let mut hb_digest = MapDigest::default();
+    let mut std_digest = MapDigest::default();
+
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // hashbrown
+        {
+            let start = Instant::now();
+            let mut snap = hb_map.clone();
+            let cloned_capacity = snap.capacity();
+            for &k in &updates {
+                if let Some(v) = snap.get_mut(&k) {
+                    *v = -*v;
+                }
+            }
+            for &k in &fresh {
+                snap.insert(k, k);
+            }
+            let elapsed = start.elapsed().as_nanos();
+            hb_grew = snap.capacity() != cloned_capacity;
+            black_box(&snap);
+            if iter >= WARMUP_ITERATIONS {
+                hb_stats.add(elapsed);
+            }
+            if verify() {
+                hb_digest = map_digest(&snap);
+            }
+        }
+
+        // std HashMap
+        {
+            let start = Instant::now();
+            let mut snap = std_map.clone();
+            let cloned_capacity = snap.capacity();
+            for &k in &updates {
+                if let Some(v) = snap.get_mut(&k) {
+                    *v = -*v;
+                }
+            }
+            for &k in &fresh {
+                snap.insert(k, k);
+            }
+            let elapsed = start.elapsed().as_nanos();
+            std_grew = snap.capacity() != cloned_capacity;
+            black_box(&snap);
+            if iter >= WARMUP_ITERATIONS {
+                std_stats.add(elapsed);
+            }
+            if verify() {
+                std_digest = map_digest(&snap);
+            }
+        }
+    }
+
+    if verify() {
+        verify_maps_equal("Clone+mutate", hb_digest, std_digest);
+    }
+
+    let (hb_ns, std_ns) = (hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    let speedup = std_ns / hb_ns;
+    let indicator = if speedup >= 1.0 { "+" } else { "-" };
+    let grew = |g: bool| if g { "grew" } else { "kept" };
+    println!(
+        "  {:<14} hb    {:>8.1}ns/op   std {:>8.1}ns/op   {}{:.2}x   capacity hb {} std {}",
+        "Clone+mutate",
+        hb_ns,
+        std_ns,
+        indicator,
+        speedup,
+        grew(hb_grew),
+        grew(std_grew)
+    );
+}
+
 // ============================================================================
 // Iteration Benchmarks
 // ============================================================================
@@ -579,6 +1884,8 @@ fn bench_remove_and_reinsert(n: usize) {
 fn bench_iteration(n: usize) {
     let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_result = 0;
+    let mut std_result = 0;
 
     // Setup maps
     let mut hb_map: HashMap<i64, i64> = HashMap::with_capacity(n);
@@ -598,6 +1905,7 @@ fn bench_iteration(n: usize) {
             }
             let elapsed = start.elapsed().as_nanos();
             black_box(sum);
+            hb_result = sum;
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
@@ -612,18 +1920,25 @@ fn bench_iteration(n: usize) {
             }
             let elapsed = start.elapsed().as_nanos();
             black_box(sum);
+            std_result = sum;
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
         }
     }
 
+    if verify() {
+        verify_results_equal("Iterate", hb_result, std_result);
+    }
+
     print_result("Iterate", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
 }
 
 fn bench_keys_iteration(n: usize) {
     let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_result = 0;
+    let mut std_result = 0;
 
     // Setup maps
     let mut hb_map: HashMap<i64, i64> = HashMap::with_capacity(n);
@@ -643,6 +1958,7 @@ fn bench_keys_iteration(n: usize) {
             }
             let elapsed = start.elapsed().as_nanos();
             black_box(sum);
+            hb_result = sum;
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
@@ -657,263 +1973,1789 @@ fn bench_keys_iteration(n: usize) {
             }
             let elapsed = start.elapsed().as_nanos();
             black_box(sum);
+            std_result = sum;
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
         }
     }
 
+    if verify() {
+        verify_results_equal("Keys iter", hb_result, std_result);
+    }
+
     print_result("Keys iter", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
 }
 
-// ============================================================================
-// Entry API Benchmarks
-// ============================================================================
+/// Times `keys().copied().collect()` followed by `sort_unstable()`.
+///
+/// Keys are random u64s so the sort is never handed pre-sorted input; how
+/// scrambled each table's iteration order is shows up in the sort sub-line.
+fn bench_sorted_keys_export(n: usize) {
+    let mut collect_stats = (Stats::new(), Stats::new());
+    let mut sort_stats = (Stats::new(), Stats::new());
+    let mut total_stats = (Stats::new(), Stats::new());
+    let mut hb_digest = MapDigest::default();
+    let mut std_digest = MapDigest::default();
 
-fn bench_entry_api(n: usize) {
-    let mut hb_stats = Stats::new();
-    let mut std_stats = Stats::new();
+    let mut rng = Rng::new(86420);
+    let mut hb_map: HashMap<u64, u64> = HashMap::with_capacity(n);
+    let mut std_map: StdHashMap<u64, u64> = StdHashMap::with_capacity(n);
+    for _ in 0..n {
+        let k = rng.next();
+        hb_map.insert(k, k);
+        std_map.insert(k, k);
+    }
 
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
-        // hashbrown entry API
+        // hashbrown
         {
-            let mut map: HashMap<i64, i64> = HashMap::new();
             let start = Instant::now();
-            for i in 0..n {
-                let key = (i % (n / 2)) as i64; // 50% duplicates
-                *map.entry(key).or_insert(0) += 1;
-            }
-            let elapsed = start.elapsed().as_nanos();
-            black_box(&map);
+            let mut keys: Vec<u64> = hb_map.keys().copied().collect();
+            let collected = Instant::now();
+            keys.sort_unstable();
+            let sorted = Instant::now();
+            black_box(&keys);
             if iter >= WARMUP_ITERATIONS {
-                hb_stats.add(elapsed);
+                collect_stats.0.add((collected - start).as_nanos());
+                sort_stats.0.add((sorted - collected).as_nanos());
+                total_stats.0.add((sorted - start).as_nanos());
+            }
+            if verify() {
+                // Pairing each key with its position makes the digest order-sensitive
+                hb_digest = map_digest(keys.iter().zip(0usize..));
             }
         }
 
-        // std HashMap entry API
+        // std HashMap
         {
-            let mut map: StdHashMap<i64, i64> = StdHashMap::new();
             let start = Instant::now();
-            for i in 0..n {
-                let key = (i % (n / 2)) as i64;
-                *map.entry(key).or_insert(0) += 1;
-            }
-            let elapsed = start.elapsed().as_nanos();
-            black_box(&map);
+            let mut keys: Vec<u64> = std_map.keys().copied().collect();
+            let collected = Instant::now();
+            keys.sort_unstable();
+            let sorted = Instant::now();
+            black_box(&keys);
             if iter >= WARMUP_ITERATIONS {
-                std_stats.add(elapsed);
+                collect_stats.1.add((collected - start).as_nanos());
+                sort_stats.1.add((sorted - collected).as_nanos());
+                total_stats.1.add((sorted - start).as_nanos());
+            }
+            if verify() {
+                std_digest = map_digest(keys.iter().zip(0usize..));
             }
         }
     }
 
-    print_result("Entry API", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    if verify() {
+        verify_maps_equal("Sorted export", hb_digest, std_digest);
+    }
+
+    print_result(
+        "Sorted export",
+        total_stats.0.ns_per_op(n),
+        total_stats.1.ns_per_op(n),
+    );
+    print_result(
+        "  collect",
+        collect_stats.0.ns_per_op(n),
+        collect_stats.1.ns_per_op(n),
+    );
+    print_result(
+        "  sort",
+        sort_stats.0.ns_per_op(n),
+        sort_stats.1.ns_per_op(n),
+    );
+}
+
+/// Rounds of `expensive_transform`, about 200ns per call on a ~3GHz core.
+const EXPENSIVE_ROUNDS: usize = 100;
+
+/// Dependent multiply-xorshift chain standing in for a costly per-value function.
+#[inline(never)]
+fn expensive_transform(v: i64) -> i64 {
+    let mut x = v as u64;
+    for _ in 0..EXPENSIVE_ROUNDS {
+        x = x
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        x ^= x >> 29;
+    }
+    x as i64
+}
+
+/// Transforms every value of an n-entry map in place and by rebuilding.
+///
+/// `iter_mut` rewrites values on an untimed clone; the rebuild collects
+/// `iter().map(..)` into a fresh map, so it doubles as a `collect` data point
+/// for an exactly-sized iterator. The expensive transform only runs up to 1M
+/// entries, where it already takes ~0.2s per pass.
+fn bench_transform_values(n: usize) {
+    let hb_map: HashMap<i64, i64> = (0..n as i64).map(|i| (i, i)).collect();
+    let std_map: StdHashMap<i64, i64> = (0..n as i64).map(|i| (i, i)).collect();
+
+    let cheap: fn(i64) -> i64 = |v| v * 2;
+    let mut transforms = vec![("Map (iter_mut)", "Map (rebuild)", cheap)];
+    if n <= 1_000_000 {
+        let expensive: fn(i64) -> i64 = expensive_transform;
+        transforms.push(("Map heavy mut", "Map heavy rbld", expensive));
+    }
+
+    for (mut_name, rebuild_name, f) in transforms {
+        let mut hb_mut = Stats::new();
+        let mut hb_rebuild = Stats::new();
+        let mut std_mut = Stats::new();
+        let mut std_rebuild = Stats::new();
+        let mut digests = [MapDigest::default(); 4];
+
+        for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+            // hashbrown iter_mut
+            {
+                let mut map = hb_map.clone();
+                let start = Instant::now();
+                for (_, v) in map.iter_mut() {
+                    *v = f(*v);
+                }
+                let elapsed = start.elapsed().as_nanos();
+                black_box(&map);
+                if iter >= WARMUP_ITERATIONS {
+                    hb_mut.add(elapsed);
+                }
+                if verify() {
+                    digests[0] = map_digest(&map);
+                }
+            }
+
+            // hashbrown rebuild
+            {
+                let start = Instant::now();
+                let map: HashMap<i64, i64> = hb_map.iter().map(|(&k, &v)| (k, f(v))).collect();
+                let elapsed = start.elapsed().as_nanos();
+                black_box(&map);
+                if iter >= WARMUP_ITERATIONS {
+                    hb_rebuild.add(elapsed);
+                }
+                if verify() {
+                    digests[1] = map_digest(&map);
+                }
+            }
+
+            // std HashMap iter_mut
+            {
+                let mut map = std_map.clone();
+                let start = Instant::now();
+                for (_, v) in map.iter_mut() {
+                    *v = f(*v);
+                }
+                let elapsed = start.elapsed().as_nanos();
+                black_box(&map);
+                if iter >= WARMUP_ITERATIONS {
+                    std_mut.add(elapsed);
+                }
+                if verify() {
+                    digests[2] = map_digest(&map);
+                }
+            }
+
+            // std HashMap rebuild
+            {
+                let start = Instant::now();
+                let map: StdHashMap<i64, i64> = std_map.iter().map(|(&k, &v)| (k, f(v))).collect();
+                let elapsed = start.elapsed().as_nanos();
+                black_box(&map);
+                if iter >= WARMUP_ITERATIONS {
+                    std_rebuild.add(elapsed);
+                }
+                if verify() {
+                    digests[3] = map_digest(&map);
+                }
+            }
+        }
+
+        if verify() {
+            verify_maps_equal(mut_name, digests[0], digests[2]);
+            verify_maps_equal(rebuild_name, digests[1], digests[3]);
+            verify_maps_equal(rebuild_name, digests[0], digests[1]);
+        }
+
+        print_result(mut_name, hb_mut.ns_per_op(n), std_mut.ns_per_op(n));
+        print_result(
+            rebuild_name,
+            hb_rebuild.ns_per_op(n),
+            std_rebuild.ns_per_op(n),
+        );
+    }
 }
 
 // ============================================================================
-// Set Benchmarks
+// Map Diff Benchmarks
 // ============================================================================
 
-fn bench_set_insert(n: usize) {
+/// Diffs two n-entry snapshots whose key sets overlap by 90%.
+///
+/// `Key diff` collects the keys of `a` missing from `b`. `Sym diff` produces
+/// the full reconciliation (added, removed, changed-value) by walking both
+/// maps; every 8th shared key carries a different value in `b`. Results are
+/// reported per key examined: n for `Key diff`, 2n for `Sym diff`.
+fn bench_map_key_diff(n: usize) {
+    let shift = n / 10;
+    let b_value = |k: i64| if k % 8 == 0 { -k } else { k };
+
+    let mut hb_a: HashMap<i64, i64> = HashMap::with_capacity(n);
+    let mut hb_b: HashMap<i64, i64> = HashMap::with_capacity(n);
+    let mut std_a: StdHashMap<i64, i64> = StdHashMap::with_capacity(n);
+    let mut std_b: StdHashMap<i64, i64> = StdHashMap::with_capacity(n);
+    for i in 0..n {
+        let (ka, kb) = (i as i64, (i + shift) as i64);
+        hb_a.insert(ka, ka);
+        hb_b.insert(kb, b_value(kb));
+        std_a.insert(ka, ka);
+        std_b.insert(kb, b_value(kb));
+    }
+
+    // Key diff: a \ b
+    {
+        let mut hb_stats = Stats::new();
+        let mut std_stats = Stats::new();
+        let mut hb_len = 0;
+        let mut std_len = 0;
+
+        for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+            // hashbrown
+            {
+                let start = Instant::now();
+                let removed: Vec<i64> = hb_a
+                    .keys()
+                    .filter(|k| !hb_b.contains_key(*k))
+                    .copied()
+                    .collect();
+                let elapsed = start.elapsed().as_nanos();
+                black_box(&removed);
+                hb_len = removed.len();
+                if iter >= WARMUP_ITERATIONS {
+                    hb_stats.add(elapsed);
+                }
+            }
+
+            // std HashMap
+            {
+                let start = Instant::now();
+                let removed: Vec<i64> = std_a
+                    .keys()
+                    .filter(|k| !std_b.contains_key(*k))
+                    .copied()
+                    .collect();
+                let elapsed = start.elapsed().as_nanos();
+                black_box(&removed);
+                std_len = removed.len();
+                if iter >= WARMUP_ITERATIONS {
+                    std_stats.add(elapsed);
+                }
+            }
+        }
+
+        if verify() {
+            verify_results_equal("Key diff", hb_len, std_len);
+        }
+
+        print_result("Key diff", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    }
+
+    // Symmetric diff: (added, removed, changed)
+    {
+        let mut hb_stats = Stats::new();
+        let mut std_stats = Stats::new();
+        let mut hb_lens = (0, 0, 0);
+        let mut std_lens = (0, 0, 0);
+
+        for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+            // hashbrown
+            {
+                let start = Instant::now();
+                let mut removed: Vec<i64> = Vec::new();
+                let mut changed: Vec<i64> = Vec::new();
+                for (k, v) in &hb_a {
+                    match hb_b.get(k) {
+                        None => removed.push(*k),
+                        Some(bv) if bv != v => changed.push(*k),
+                        Some(_) => {}
+                    }
+                }
+                let added: Vec<i64> = hb_b
+                    .keys()
+                    .filter(|k| !hb_a.contains_key(*k))
+                    .copied()
+                    .collect();
+                let elapsed = start.elapsed().as_nanos();
+                black_box((&added, &removed, &changed));
+                hb_lens = (added.len(), removed.len(), changed.len());
+                if iter >= WARMUP_ITERATIONS {
+                    hb_stats.add(elapsed);
+                }
+            }
+
+            // std HashMap
+            {
+                let start = Instant::now();
+                let mut removed: Vec<i64> = Vec::new();
+                let mut changed: Vec<i64> = Vec::new();
+                for (k, v) in &std_a {
+                    match std_b.get(k) {
+                        None => removed.push(*k),
+                        Some(bv) if bv != v => changed.push(*k),
+                        Some(_) => {}
+                    }
+                }
+                let added: Vec<i64> = std_b
+                    .keys()
+                    .filter(|k| !std_a.contains_key(*k))
+                    .copied()
+                    .collect();
+                let elapsed = start.elapsed().as_nanos();
+                black_box((&added, &removed, &changed));
+                std_lens = (added.len(), removed.len(), changed.len());
+                if iter >= WARMUP_ITERATIONS {
+                    std_stats.add(elapsed);
+                }
+            }
+        }
+
+        if verify() {
+            verify_results_equal("Sym diff", hb_lens, std_lens);
+        }
+
+        print_result(
+            "Sym diff",
+            hb_stats.ns_per_op(n * 2),
+            std_stats.ns_per_op(n * 2),
+        );
+    }
+}
+
+// ============================================================================
+// Entry API Benchmarks
+// ============================================================================
+
+fn bench_entry_api(n: usize) {
     let mut hb_stats = Stats::new();
     let mut std_stats = Stats::new();
+    let mut hb_digest = MapDigest::default();
+    let mut std_digest = MapDigest::default();
 
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
-        // hashbrown HashSet
+        // hashbrown entry API
         {
-            let mut set: HashSet<i64> = HashSet::new();
+            let mut map: HashMap<i64, i64> = HashMap::new();
             let start = Instant::now();
             for i in 0..n {
-                set.insert(i as i64);
+                let key = (i % (n / 2)) as i64; // 50% duplicates
+                *map.entry(key).or_insert(0) += 1;
             }
             let elapsed = start.elapsed().as_nanos();
-            black_box(&set);
+            black_box(&map);
             if iter >= WARMUP_ITERATIONS {
                 hb_stats.add(elapsed);
             }
+            if verify() {
+                hb_digest = map_digest(&map);
+            }
         }
 
-        // std HashSet
+        // std HashMap entry API
         {
-            let mut set: StdHashSet<i64> = StdHashSet::new();
+            let mut map: StdHashMap<i64, i64> = StdHashMap::new();
             let start = Instant::now();
             for i in 0..n {
-                set.insert(i as i64);
+                let key = (i % (n / 2)) as i64;
+                *map.entry(key).or_insert(0) += 1;
             }
             let elapsed = start.elapsed().as_nanos();
-            black_box(&set);
+            black_box(&map);
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
+            if verify() {
+                std_digest = map_digest(&map);
+            }
         }
     }
 
-    print_result("Set insert", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
-}
+    if verify() {
+        verify_maps_equal("Entry API", hb_digest, std_digest);
+    }
 
-fn bench_set_contains(n: usize) {
-    let mut hb_stats = Stats::new();
-    let mut std_stats = Stats::new();
+    print_result("Entry API", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+}
 
-    // Setup sets
-    let mut hb_set: HashSet<i64> = HashSet::with_capacity(n);
-    let mut std_set: StdHashSet<i64> = StdHashSet::with_capacity(n);
-    for i in 0..n {
-        hb_set.insert(i as i64);
-        std_set.insert(i as i64);
+/// Compares insert-if-absent idioms over a stream where half the keys exist.
+///
+/// The entry idiom matches on `Entry` rather than calling `or_insert` so that,
+/// like the other two, it reports whether the key was already present. std has
+/// no `try_insert`, so that line is compared against std's entry idiom.
+#[allow(clippy::map_entry)] // the double-lookup idiom is what's being measured
+fn bench_insert_if_absent(n: usize) {
+    let existing = (n / 2).max(1);
+    let mut hb_base: HashMap<i64, i64> = HashMap::with_capacity(n);
+    let mut std_base: StdHashMap<i64, i64> = StdHashMap::with_capacity(n);
+    for i in 0..existing {
+        hb_base.insert(i as i64, i as i64);
+        std_base.insert(i as i64, i as i64);
     }
 
+    // 50% of the stream hits pre-existing keys, the rest are fresh
+    let mut rng = Rng::new(31415);
+    let stream: Vec<i64> = (0..n)
+        .map(|i| {
+            if rng.next() & 1 == 0 {
+                rng.next_bounded(existing as u64) as i64
+            } else {
+                (existing + i) as i64
+            }
+        })
+        .collect();
+
+    let mut hb_double = Stats::new();
+    let mut hb_entry = Stats::new();
+    let mut hb_try = Stats::new();
+    let mut std_double = Stats::new();
+    let mut std_entry = Stats::new();
+    let mut digests = [MapDigest::default(); 5];
+
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
-        // hashbrown
+        let mut present = [0usize; 5];
+
+        // hashbrown contains_key + insert
         {
-            let mut count: usize = 0;
+            let mut map = hb_base.clone();
             let start = Instant::now();
-            for i in 0..(n * 2) {
-                if hb_set.contains(&(i as i64)) {
-                    count += 1;
+            for &k in &stream {
+                if !map.contains_key(&k) {
+                    map.insert(k, k);
+                } else {
+                    present[0] += 1;
                 }
             }
             let elapsed = start.elapsed().as_nanos();
-            black_box(count);
+            black_box(&map);
             if iter >= WARMUP_ITERATIONS {
-                hb_stats.add(elapsed);
+                hb_double.add(elapsed);
+            }
+            if verify() {
+                digests[0] = map_digest(&map);
             }
         }
 
-        // std HashSet
+        // hashbrown entry
         {
-            let mut count: usize = 0;
+            let mut map = hb_base.clone();
             let start = Instant::now();
-            for i in 0..(n * 2) {
-                if std_set.contains(&(i as i64)) {
-                    count += 1;
+            for &k in &stream {
+                match map.entry(k) {
+                    hashbrown::hash_map::Entry::Occupied(_) => present[1] += 1,
+                    hashbrown::hash_map::Entry::Vacant(e) => {
+                        e.insert(k);
+                    }
                 }
             }
             let elapsed = start.elapsed().as_nanos();
-            black_box(count);
+            black_box(&map);
             if iter >= WARMUP_ITERATIONS {
-                std_stats.add(elapsed);
+                hb_entry.add(elapsed);
+            }
+            if verify() {
+                digests[1] = map_digest(&map);
             }
         }
-    }
 
-    print_result("Set contains", hb_stats.ns_per_op(n * 2), std_stats.ns_per_op(n * 2));
-}
-
-fn bench_set_union(n: usize) {
-    let mut hb_stats = Stats::new();
-    let mut std_stats = Stats::new();
-
-    // Setup sets with 50% overlap
-    let mut hb_set_a: HashSet<i64> = HashSet::with_capacity(n);
-    let mut hb_set_b: HashSet<i64> = HashSet::with_capacity(n);
-    let mut std_set_a: StdHashSet<i64> = StdHashSet::with_capacity(n);
-    let mut std_set_b: StdHashSet<i64> = StdHashSet::with_capacity(n);
-
-    for i in 0..n {
-        hb_set_a.insert(i as i64);
-        hb_set_b.insert((i + n / 2) as i64);
-        std_set_a.insert(i as i64);
-        std_set_b.insert((i + n / 2) as i64);
-    }
+        // hashbrown try_insert
+        {
+            let mut map = hb_base.clone();
+            let start = Instant::now();
+            for &k in &stream {
+                if map.try_insert(k, k).is_err() {
+                    present[2] += 1;
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                hb_try.add(elapsed);
+            }
+            if verify() {
+                digests[2] = map_digest(&map);
+            }
+        }
 
-    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
-        // hashbrown
+        // std HashMap contains_key + insert
         {
+            let mut map = std_base.clone();
             let start = Instant::now();
-            let result: HashSet<i64> = hb_set_a.union(&hb_set_b).copied().collect();
+            for &k in &stream {
+                if !map.contains_key(&k) {
+                    map.insert(k, k);
+                } else {
+                    present[3] += 1;
+                }
+            }
             let elapsed = start.elapsed().as_nanos();
-            black_box(&result);
+            black_box(&map);
             if iter >= WARMUP_ITERATIONS {
-                hb_stats.add(elapsed);
+                std_double.add(elapsed);
+            }
+            if verify() {
+                digests[3] = map_digest(&map);
             }
         }
 
-        // std HashSet
+        // std HashMap entry
         {
+            let mut map = std_base.clone();
             let start = Instant::now();
-            let result: StdHashSet<i64> = std_set_a.union(&std_set_b).copied().collect();
+            for &k in &stream {
+                match map.entry(k) {
+                    std::collections::hash_map::Entry::Occupied(_) => present[4] += 1,
+                    std::collections::hash_map::Entry::Vacant(e) => {
+                        e.insert(k);
+                    }
+                }
+            }
             let elapsed = start.elapsed().as_nanos();
-            black_box(&result);
+            black_box(&map);
             if iter >= WARMUP_ITERATIONS {
-                std_stats.add(elapsed);
+                std_entry.add(elapsed);
+            }
+            if verify() {
+                digests[4] = map_digest(&map);
             }
         }
+
+        assert!(present.iter().all(|&p| p == present[0]));
     }
 
-    print_result("Set union", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    if verify() {
+        verify_maps_equal("Absent (2x)", digests[0], digests[3]);
+        verify_maps_equal("Absent (entry)", digests[1], digests[4]);
+        verify_maps_equal("Absent (try)", digests[2], digests[4]);
+    }
+
+    print_result(
+        "Absent (2x)",
+        hb_double.ns_per_op(n),
+        std_double.ns_per_op(n),
+    );
+    print_result(
+        "Absent (entry)",
+        hb_entry.ns_per_op(n),
+        std_entry.ns_per_op(n),
+    );
+    print_result("Absent (try)", hb_try.ns_per_op(n), std_entry.ns_per_op(n));
 }
 
-fn bench_set_intersection(n: usize) {
-    let mut hb_stats = Stats::new();
-    let mut std_stats = Stats::new();
+// ============================================================================
+// String Workloads
+// ============================================================================
 
-    // Setup sets with 50% overlap
-    let mut hb_set_a: HashSet<i64> = HashSet::with_capacity(n);
-    let mut hb_set_b: HashSet<i64> = HashSet::with_capacity(n);
-    let mut std_set_a: StdHashSet<i64> = StdHashSet::with_capacity(n);
-    let mut std_set_b: StdHashSet<i64> = StdHashSet::with_capacity(n);
+/// Interns a Zipf-distributed stream of n tokens drawn from n/20 distinct strings.
+///
+/// hashbrown uses `entry_ref`, which hashes the borrowed `&str` and only
+/// allocates a `String` when the token is new. The naive idiom clones every
+/// token into an owned key before the lookup. Both are shown for hashbrown so
+/// the idiom and the table can be told apart; std only has the naive path.
+fn bench_string_interning(n: usize) {
+    let distinct = (n / 20).max(1);
+    let mut rng = Rng::new(97531);
+    let vocab: Vec<String> = (0..distinct)
+        .map(|i| format!("sym_{}_{:x}", i, rng.next() >> 40))
+        .collect();
+    let tokens: Vec<&str> = keygen::zipf_ranks(&mut rng, distinct, n, 1.0)
+        .into_iter()
+        .map(|r| vocab[r].as_str())
+        .collect();
 
-    for i in 0..n {
-        hb_set_a.insert(i as i64);
-        hb_set_b.insert((i + n / 2) as i64);
-        std_set_a.insert(i as i64);
-        std_set_b.insert((i + n / 2) as i64);
-    }
+    let mut hb_ref_stats = Stats::new();
+    let mut hb_naive_stats = Stats::new();
+    let mut std_stats = Stats::new();
+    let mut hb_ref_allocs = 0;
+    let mut hb_naive_allocs = 0;
+    let mut std_allocs = 0;
+    let mut digests = [MapDigest::default(); 3];
 
     for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
-        // hashbrown
+        // hashbrown entry_ref (allocates only on first sighting)
         {
+            let mut map: HashMap<String, u32> = HashMap::new();
+            let mut checksum: u32 = 0;
+            let before = alloc_track::snapshot();
             let start = Instant::now();
-            let result: HashSet<i64> = hb_set_a.intersection(&hb_set_b).copied().collect();
+            for &tok in &tokens {
+                let next = map.len() as u32;
+                checksum = checksum.wrapping_add(*map.entry_ref(tok).or_insert(next));
+            }
             let elapsed = start.elapsed().as_nanos();
-            black_box(&result);
+            hb_ref_allocs = alloc_track::snapshot().since(before).allocations;
+            black_box(checksum);
+            black_box(&map);
             if iter >= WARMUP_ITERATIONS {
-                hb_stats.add(elapsed);
+                hb_ref_stats.add(elapsed);
+            }
+            if verify() {
+                digests[0] = map_digest(&map);
             }
         }
 
-        // std HashSet
+        // hashbrown naive (clone on every entry)
         {
+            let mut map: HashMap<String, u32> = HashMap::new();
+            let mut checksum: u32 = 0;
+            let before = alloc_track::snapshot();
             let start = Instant::now();
-            let result: StdHashSet<i64> = std_set_a.intersection(&std_set_b).copied().collect();
+            for &tok in &tokens {
+                let next = map.len() as u32;
+                checksum = checksum.wrapping_add(*map.entry(tok.to_string()).or_insert(next));
+            }
             let elapsed = start.elapsed().as_nanos();
-            black_box(&result);
+            hb_naive_allocs = alloc_track::snapshot().since(before).allocations;
+            black_box(checksum);
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                hb_naive_stats.add(elapsed);
+            }
+            if verify() {
+                digests[1] = map_digest(&map);
+            }
+        }
+
+        // std HashMap naive (clone on every entry)
+        {
+            let mut map: StdHashMap<String, u32> = StdHashMap::new();
+            let mut checksum: u32 = 0;
+            let before = alloc_track::snapshot();
+            let start = Instant::now();
+            for &tok in &tokens {
+                let next = map.len() as u32;
+                checksum = checksum.wrapping_add(*map.entry(tok.to_string()).or_insert(next));
+            }
+            let elapsed = start.elapsed().as_nanos();
+            std_allocs = alloc_track::snapshot().since(before).allocations;
+            black_box(checksum);
+            black_box(&map);
             if iter >= WARMUP_ITERATIONS {
                 std_stats.add(elapsed);
             }
+            if verify() {
+                digests[2] = map_digest(&map);
+            }
         }
     }
 
-    print_result("Set intersect", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
-}
+    if verify() {
+        verify_maps_equal("Intern (ref)", digests[0], digests[2]);
+        verify_maps_equal("Intern (naive)", digests[1], digests[2]);
+    }
 
-// ============================================================================
-// Memory Usage
-// ============================================================================
+    print_result(
+        "Intern (ref)",
+        hb_ref_stats.ns_per_op(n),
+        std_stats.ns_per_op(n),
+    );
+    print_result(
+        "Intern (naive)",
+        hb_naive_stats.ns_per_op(n),
+        std_stats.ns_per_op(n),
+    );
+    if alloc_track::enabled() {
+        println!(
+            "  {:<14} hb ref {:>6}   hb naive {:>6}   std {:>6}   allocs per 1K tokens",
+            "Intern allocs",
+            hb_ref_allocs * 1000 / n,
+            hb_naive_allocs * 1000 / n,
+            std_allocs * 1000 / n
+        );
+    }
+}
 
-fn bench_memory_usage(n: usize) {
-    // Note: Rust doesn't expose internal capacity details as easily
-    // This is an approximation based on known load factors
+/// Counts a stream of n tokens where 90% of tokens repeat an earlier one.
+///
+/// Three upsert idioms: `entry(tok.to_string())` allocates an owned key on
+/// every call, `contains_key` + `insert` only allocates for new keys but
+/// probes twice, and hashbrown's `entry_ref(tok)` probes once and allocates
+/// only for new keys. std has no `entry_ref`, so `Upsert (ref)` is compared
+/// against std's double lookup, its cheapest non-allocating option.
+fn bench_string_upsert(n: usize) {
+    let distinct = (n / 10).max(1);
+    let mut rng = Rng::new(27182);
+    let vocab: Vec<String> = (0..distinct)
+        .map(|i| format!("tok_{}_{:x}", i, rng.next() >> 40))
+        .collect();
+    let tokens: Vec<&str> = (0..n)
+        .map(|_| vocab[rng.next_bounded(distinct as u64) as usize].as_str())
+        .collect();
 
-    // hashbrown: 87.5% load factor, 1 byte control per slot
-    let hb_capacity = (n as f64 / 0.875).ceil() as usize;
-    let hb_entry_size = std::mem::size_of::<(i64, i64)>();
-    let hb_ctrl_size = hb_capacity + 16; // GROUP_WIDTH padding
-    let hb_mem = hb_ctrl_size + hb_capacity * hb_entry_size;
+    let mut hb_owned = Stats::new();
+    let mut hb_double = Stats::new();
+    let mut hb_ref = Stats::new();
+    let mut std_owned = Stats::new();
+    let mut std_double = Stats::new();
+    let mut allocs = [0usize; 5];
+    let mut digests = [MapDigest::default(); 5];
 
-    // std HashMap: ~90% load factor (varies), different layout
-    let std_capacity = (n as f64 / 0.9).ceil() as usize;
-    let std_entry_size = std::mem::size_of::<(i64, i64)>() + 8; // key+value+hash
-    let std_mem = std_capacity * std_entry_size;
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // hashbrown entry(owned)
+        {
+            let mut map: HashMap<String, u32> = HashMap::new();
+            let before = alloc_track::snapshot();
+            let start = Instant::now();
+            for &tok in &tokens {
+                *map.entry(tok.to_string()).or_insert(0) += 1;
+            }
+            let elapsed = start.elapsed().as_nanos();
+            allocs[0] = alloc_track::snapshot().since(before).allocations;
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                hb_owned.add(elapsed);
+            }
+            if verify() {
+                digests[0] = map_digest(&map);
+            }
+        }
+
+        // hashbrown contains_key + insert
+        {
+            let mut map: HashMap<String, u32> = HashMap::new();
+            let before = alloc_track::snapshot();
+            let start = Instant::now();
+            for &tok in &tokens {
+                if !map.contains_key(tok) {
+                    map.insert(tok.to_string(), 0);
+                }
+                *map.get_mut(tok).unwrap() += 1;
+            }
+            let elapsed = start.elapsed().as_nanos();
+            allocs[1] = alloc_track::snapshot().since(before).allocations;
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                hb_double.add(elapsed);
+            }
+            if verify() {
+                digests[1] = map_digest(&map);
+            }
+        }
+
+        // hashbrown entry_ref
+        {
+            let mut map: HashMap<String, u32> = HashMap::new();
+            let before = alloc_track::snapshot();
+            let start = Instant::now();
+            for &tok in &tokens {
+                *map.entry_ref(tok).or_insert(0) += 1;
+            }
+            let elapsed = start.elapsed().as_nanos();
+            allocs[2] = alloc_track::snapshot().since(before).allocations;
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                hb_ref.add(elapsed);
+            }
+            if verify() {
+                digests[2] = map_digest(&map);
+            }
+        }
+
+        // std HashMap entry(owned)
+        {
+            let mut map: StdHashMap<String, u32> = StdHashMap::new();
+            let before = alloc_track::snapshot();
+            let start = Instant::now();
+            for &tok in &tokens {
+                *map.entry(tok.to_string()).or_insert(0) += 1;
+            }
+            let elapsed = start.elapsed().as_nanos();
+            allocs[3] = alloc_track::snapshot().since(before).allocations;
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                std_owned.add(elapsed);
+            }
+            if verify() {
+                digests[3] = map_digest(&map);
+            }
+        }
+
+        // std HashMap contains_key + insert
+        {
+            let mut map: StdHashMap<String, u32> = StdHashMap::new();
+            let before = alloc_track::snapshot();
+            let start = Instant::now();
+            for &tok in &tokens {
+                if !map.contains_key(tok) {
+                    map.insert(tok.to_string(), 0);
+                }
+                *map.get_mut(tok).unwrap() += 1;
+            }
+            let elapsed = start.elapsed().as_nanos();
+            allocs[4] = alloc_track::snapshot().since(before).allocations;
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                std_double.add(elapsed);
+            }
+            if verify() {
+                digests[4] = map_digest(&map);
+            }
+        }
+    }
+
+    if verify() {
+        verify_maps_equal("Upsert (owned)", digests[0], digests[3]);
+        verify_maps_equal("Upsert (2x)", digests[1], digests[4]);
+        verify_maps_equal("Upsert (ref)", digests[2], digests[4]);
+    }
+
+    print_result(
+        "Upsert (owned)",
+        hb_owned.ns_per_op(n),
+        std_owned.ns_per_op(n),
+    );
+    print_result(
+        "Upsert (2x)",
+        hb_double.ns_per_op(n),
+        std_double.ns_per_op(n),
+    );
+    print_result("Upsert (ref)", hb_ref.ns_per_op(n), std_double.ns_per_op(n));
+    if alloc_track::enabled() {
+        println!(
+            "  {:<14} hb owned {:>6}   hb 2x {:>6}   hb ref {:>6}   std owned {:>6}   std 2x {:>6}   \
+             allocs per 1K tokens",
+            "Upsert allocs",
+            allocs[0] * 1000 / n,
+            allocs[1] * 1000 / n,
+            allocs[2] * 1000 / n,
+            allocs[3] * 1000 / n,
+            allocs[4] * 1000 / n
+        );
+    }
+}
+
+// ============================================================================
+// Single-Key Benchmarks
+// ============================================================================
+
+/// Performs n insert/get/entry operations against one key.
+///
+/// With a single resident key every probe hits the same group in L1, so these
+/// numbers are the per-operation floor (hash + one group probe) that the other
+/// benchmarks can be compared against. The key is routed through `black_box`
+/// so the hash cannot be hoisted out of the loop.
+fn bench_single_key(n: usize) {
+    const KEY: i64 = 42;
+
+    // insert(k, i) overwrites
+    {
+        let mut hb_stats = Stats::new();
+        let mut std_stats = Stats::new();
+        let mut hb_digest = MapDigest::default();
+        let mut std_digest = MapDigest::default();
+
+        for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+            // hashbrown
+            {
+                let mut map: HashMap<i64, i64> = HashMap::new();
+                let start = Instant::now();
+                for i in 0..n {
+                    map.insert(black_box(KEY), i as i64);
+                }
+                let elapsed = start.elapsed().as_nanos();
+                black_box(&map);
+                if iter >= WARMUP_ITERATIONS {
+                    hb_stats.add(elapsed);
+                }
+                if verify() {
+                    hb_digest = map_digest(&map);
+                }
+            }
+
+            // std HashMap
+            {
+                let mut map: StdHashMap<i64, i64> = StdHashMap::new();
+                let start = Instant::now();
+                for i in 0..n {
+                    map.insert(black_box(KEY), i as i64);
+                }
+                let elapsed = start.elapsed().as_nanos();
+                black_box(&map);
+                if iter >= WARMUP_ITERATIONS {
+                    std_stats.add(elapsed);
+                }
+                if verify() {
+                    std_digest = map_digest(&map);
+                }
+            }
+        }
+
+        if verify() {
+            verify_maps_equal("1-key insert", hb_digest, std_digest);
+        }
+
+        print_result(
+            "1-key insert",
+            hb_stats.ns_per_op(n),
+            std_stats.ns_per_op(n),
+        );
+    }
+
+    // get(&k)
+    {
+        let mut hb_stats = Stats::new();
+        let mut std_stats = Stats::new();
+        let mut hb_result = 0;
+        let mut std_result = 0;
+
+        let mut hb_map: HashMap<i64, i64> = HashMap::new();
+        let mut std_map: StdHashMap<i64, i64> = StdHashMap::new();
+        hb_map.insert(KEY, 1);
+        std_map.insert(KEY, 1);
+
+        for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+            // hashbrown
+            {
+                let mut checksum: i64 = 0;
+                let start = Instant::now();
+                for _ in 0..n {
+                    if let Some(&v) = hb_map.get(black_box(&KEY)) {
+                        checksum = checksum.wrapping_add(v);
+                    }
+                }
+                let elapsed = start.elapsed().as_nanos();
+                black_box(checksum);
+                hb_result = checksum;
+                if iter >= WARMUP_ITERATIONS {
+                    hb_stats.add(elapsed);
+                }
+            }
+
+            // std HashMap
+            {
+                let mut checksum: i64 = 0;
+                let start = Instant::now();
+                for _ in 0..n {
+                    if let Some(&v) = std_map.get(black_box(&KEY)) {
+                        checksum = checksum.wrapping_add(v);
+                    }
+                }
+                let elapsed = start.elapsed().as_nanos();
+                black_box(checksum);
+                std_result = checksum;
+                if iter >= WARMUP_ITERATIONS {
+                    std_stats.add(elapsed);
+                }
+            }
+        }
+
+        if verify() {
+            verify_results_equal("1-key get", hb_result, std_result);
+        }
+
+        print_result("1-key get", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+    }
+
+    // entry(k).and_modify(...)
+    {
+        let mut hb_stats = Stats::new();
+        let mut std_stats = Stats::new();
+        let mut hb_digest = MapDigest::default();
+        let mut std_digest = MapDigest::default();
+
+        for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+            // hashbrown
+            {
+                let mut map: HashMap<i64, i64> = HashMap::new();
+                let start = Instant::now();
+                for _ in 0..n {
+                    map.entry(black_box(KEY))
+                        .and_modify(|v| *v += 1)
+                        .or_insert(1);
+                }
+                let elapsed = start.elapsed().as_nanos();
+                black_box(&map);
+                if iter >= WARMUP_ITERATIONS {
+                    hb_stats.add(elapsed);
+                }
+                if verify() {
+                    hb_digest = map_digest(&map);
+                }
+            }
+
+            // std HashMap
+            {
+                let mut map: StdHashMap<i64, i64> = StdHashMap::new();
+                let start = Instant::now();
+                for _ in 0..n {
+                    map.entry(black_box(KEY))
+                        .and_modify(|v| *v += 1)
+                        .or_insert(1);
+                }
+                let elapsed = start.elapsed().as_nanos();
+                black_box(&map);
+                if iter >= WARMUP_ITERATIONS {
+                    std_stats.add(elapsed);
+                }
+                if verify() {
+                    std_digest = map_digest(&map);
+                }
+            }
+        }
+
+        if verify() {
+            verify_maps_equal("1-key modify", hb_digest, std_digest);
+        }
+
+        print_result(
+            "1-key modify",
+            hb_stats.ns_per_op(n),
+            std_stats.ns_per_op(n),
+        );
+    }
+}
+
+// ============================================================================
+// Set Benchmarks
+// ============================================================================
+
+fn bench_set_insert(n: usize) {
+    let mut hb_stats = Stats::new();
+    let mut std_stats = Stats::new();
+    let mut hb_last: HashSet<i64> = HashSet::new();
+    let mut std_last: StdHashSet<i64> = StdHashSet::new();
+
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // hashbrown HashSet
+        {
+            let mut set: HashSet<i64> = HashSet::new();
+            let start = Instant::now();
+            for i in 0..n {
+                set.insert(i as i64);
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&set);
+            if iter >= WARMUP_ITERATIONS {
+                hb_stats.add(elapsed);
+            }
+            if verify() {
+                hb_last = set;
+            }
+        }
+
+        // std HashSet
+        {
+            let mut set: StdHashSet<i64> = StdHashSet::new();
+            let start = Instant::now();
+            for i in 0..n {
+                set.insert(i as i64);
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&set);
+            if iter >= WARMUP_ITERATIONS {
+                std_stats.add(elapsed);
+            }
+            if verify() {
+                std_last = set;
+            }
+        }
+    }
+
+    if verify() {
+        verify_sets_equal("Set insert", &hb_last, &std_last);
+    }
+
+    print_result("Set insert", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+}
+
+fn bench_set_contains(n: usize) {
+    let mut hb_stats = Stats::new();
+    let mut std_stats = Stats::new();
+    let mut hb_result = 0;
+    let mut std_result = 0;
+
+    // Setup sets
+    let mut hb_set: HashSet<i64> = HashSet::with_capacity(n);
+    let mut std_set: StdHashSet<i64> = StdHashSet::with_capacity(n);
+    for i in 0..n {
+        hb_set.insert(i as i64);
+        std_set.insert(i as i64);
+    }
+
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // hashbrown
+        {
+            let mut count: usize = 0;
+            let start = Instant::now();
+            for i in 0..(n * 2) {
+                if hb_set.contains(&(i as i64)) {
+                    count += 1;
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(count);
+            hb_result = count;
+            if iter >= WARMUP_ITERATIONS {
+                hb_stats.add(elapsed);
+            }
+        }
+
+        // std HashSet
+        {
+            let mut count: usize = 0;
+            let start = Instant::now();
+            for i in 0..(n * 2) {
+                if std_set.contains(&(i as i64)) {
+                    count += 1;
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(count);
+            std_result = count;
+            if iter >= WARMUP_ITERATIONS {
+                std_stats.add(elapsed);
+            }
+        }
+    }
+
+    if verify() {
+        verify_results_equal("Set contains", hb_result, std_result);
+    }
+
+    print_result("Set contains", hb_stats.ns_per_op(n * 2), std_stats.ns_per_op(n * 2));
+}
+
+fn bench_set_union(n: usize) {
+    let mut hb_stats = Stats::new();
+    let mut std_stats = Stats::new();
+    let mut hb_last: HashSet<i64> = HashSet::new();
+    let mut std_last: StdHashSet<i64> = StdHashSet::new();
+
+    // Setup sets with 50% overlap
+    let mut hb_set_a: HashSet<i64> = HashSet::with_capacity(n);
+    let mut hb_set_b: HashSet<i64> = HashSet::with_capacity(n);
+    let mut std_set_a: StdHashSet<i64> = StdHashSet::with_capacity(n);
+    let mut std_set_b: StdHashSet<i64> = StdHashSet::with_capacity(n);
+
+    for i in 0..n {
+        hb_set_a.insert(i as i64);
+        hb_set_b.insert((i + n / 2) as i64);
+        std_set_a.insert(i as i64);
+        std_set_b.insert((i + n / 2) as i64);
+    }
+
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // hashbrown
+        {
+            let start = Instant::now();
+            let result: HashSet<i64> = hb_set_a.union(&hb_set_b).copied().collect();
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&result);
+            if iter >= WARMUP_ITERATIONS {
+                hb_stats.add(elapsed);
+            }
+            if verify() {
+                hb_last = result;
+            }
+        }
+
+        // std HashSet
+        {
+            let start = Instant::now();
+            let result: StdHashSet<i64> = std_set_a.union(&std_set_b).copied().collect();
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&result);
+            if iter >= WARMUP_ITERATIONS {
+                std_stats.add(elapsed);
+            }
+            if verify() {
+                std_last = result;
+            }
+        }
+    }
+
+    if verify() {
+        verify_sets_equal("Set union", &hb_last, &std_last);
+    }
+
+    print_result("Set union", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+}
+
+fn bench_set_intersection(n: usize) {
+    let mut hb_stats = Stats::new();
+    let mut std_stats = Stats::new();
+    let mut hb_last: HashSet<i64> = HashSet::new();
+    let mut std_last: StdHashSet<i64> = StdHashSet::new();
+
+    // Setup sets with 50% overlap
+    let mut hb_set_a: HashSet<i64> = HashSet::with_capacity(n);
+    let mut hb_set_b: HashSet<i64> = HashSet::with_capacity(n);
+    let mut std_set_a: StdHashSet<i64> = StdHashSet::with_capacity(n);
+    let mut std_set_b: StdHashSet<i64> = StdHashSet::with_capacity(n);
+
+    for i in 0..n {
+        hb_set_a.insert(i as i64);
+        hb_set_b.insert((i + n / 2) as i64);
+        std_set_a.insert(i as i64);
+        std_set_b.insert((i + n / 2) as i64);
+    }
+
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // hashbrown
+        {
+            let start = Instant::now();
+            let result: HashSet<i64> = hb_set_a.intersection(&hb_set_b).copied().collect();
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&result);
+            if iter >= WARMUP_ITERATIONS {
+                hb_stats.add(elapsed);
+            }
+            if verify() {
+                hb_last = result;
+            }
+        }
+
+        // std HashSet
+        {
+            let start = Instant::now();
+            let result: StdHashSet<i64> = std_set_a.intersection(&std_set_b).copied().collect();
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&result);
+            if iter >= WARMUP_ITERATIONS {
+                std_stats.add(elapsed);
+            }
+            if verify() {
+                std_last = result;
+            }
+        }
+    }
+
+    if verify() {
+        verify_sets_equal("Set intersect", &hb_last, &std_last);
+    }
+
+    print_result("Set intersect", hb_stats.ns_per_op(n), std_stats.ns_per_op(n));
+}
+
+/// Times one set operation over the warmup + measured iterations.
+///
+/// Returns the average ns per run and the result of the last run.
+fn time_set_op<S>(a: &S, b: &S, op: impl Fn(&S, &S) -> S) -> (f64, S) {
+    let mut stats = Stats::new();
+    let mut last = None;
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        let start = Instant::now();
+        let result = op(a, b);
+        let elapsed = start.elapsed().as_nanos();
+        black_box(&result);
+        if iter >= WARMUP_ITERATIONS {
+            stats.add(elapsed);
+        }
+        last = Some(result);
+    }
+    (stats.avg_ns() as f64, last.unwrap())
+}
+
+/// Union and intersection via operators, iterator adapters, and a manual loop.
+///
+/// `&a | &b` and `&a & &b` build their result through `FromIterator`; the
+/// iterator forms collect `union()` / `intersection()` explicitly; the loop
+/// clones the larger set and inserts the smaller one (union), or filters the
+/// smaller set into a pre-sized result (intersection). Uses the 50%-overlap
+/// fixtures of `bench_set_union`. Every idiom must produce the same set.
+fn bench_set_operators(n: usize) {
+    let hb_a: HashSet<i64> = (0..n as i64).collect();
+    let hb_b: HashSet<i64> = (n as i64 / 2..(n + n / 2) as i64).collect();
+    let std_a: StdHashSet<i64> = (0..n as i64).collect();
+    let std_b: StdHashSet<i64> = (n as i64 / 2..(n + n / 2) as i64).collect();
+
+    let hb_union = [
+        time_set_op(&hb_a, &hb_b, |a, b| a | b),
+        time_set_op(&hb_a, &hb_b, |a, b| a.union(b).copied().collect()),
+        time_set_op(&hb_a, &hb_b, |a, b| {
+            let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+            let mut out = large.clone();
+            out.extend(small.iter().copied());
+            out
+        }),
+    ];
+    let std_union = [
+        time_set_op(&std_a, &std_b, |a, b| a | b),
+        time_set_op(&std_a, &std_b, |a, b| a.union(b).copied().collect()),
+        time_set_op(&std_a, &std_b, |a, b| {
+            let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+            let mut out = large.clone();
+            out.extend(small.iter().copied());
+            out
+        }),
+    ];
+    let hb_isect = [
+        time_set_op(&hb_a, &hb_b, |a, b| a & b),
+        time_set_op(&hb_a, &hb_b, |a, b| a.intersection(b).copied().collect()),
+        time_set_op(&hb_a, &hb_b, |a, b| {
+            let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+            let mut out = HashSet::with_capacity(small.len());
+            for &x in small {
+                if large.contains(&x) {
+                    out.insert(x);
+                }
+            }
+            out
+        }),
+    ];
+    let std_isect = [
+        time_set_op(&std_a, &std_b, |a, b| a & b),
+        time_set_op(&std_a, &std_b, |a, b| a.intersection(b).copied().collect()),
+        time_set_op(&std_a, &std_b, |a, b| {
+            let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+            let mut out = StdHashSet::with_capacity(small.len());
+            for &x in small {
+                if large.contains(&x) {
+                    out.insert(x);
+                }
+            }
+            out
+        }),
+    ];
+
+    for results in [&hb_union, &hb_isect] {
+        assert!(results.iter().all(|(_, set)| *set == results[0].1));
+    }
+    for results in [&std_union, &std_isect] {
+        assert!(results.iter().all(|(_, set)| *set == results[0].1));
+    }
+    if verify() {
+        verify_sets_equal("Union ops", &hb_union[0].1, &std_union[0].1);
+        verify_sets_equal("Isect ops", &hb_isect[0].1, &std_isect[0].1);
+    }
+
+    let names = [
+        ["Union (op)", "Union (iter)", "Union (loop)"],
+        ["Isect (op)", "Isect (iter)", "Isect (loop)"],
+    ];
+    for (names, hb, std) in [
+        (names[0], &hb_union, &std_union),
+        (names[1], &hb_isect, &std_isect),
+    ] {
+        for i in 0..3 {
+            print_result(names[i], hb[i].0 / n as f64, std[i].0 / n as f64);
+        }
+    }
+}
+
+/// Counts an intersection without collecting it, for two set shapes.
+///
+/// `intersection().count()` walks whichever set is smaller; the manual filter
+/// always walks `a`. The symmetric shape reuses the 50%-overlap fixtures of
+/// `bench_set_intersection`. In the 10:1 shape `b` holds n/10 keys, half of
+/// them in `a`, so the filter walks the large side while `intersection` walks
+/// the small one. All times are per element of `a`.
+fn bench_set_intersection_count(n: usize) {
+    let small = (n / 10).max(1);
+    let shapes = [
+        ("Isect count", "Isect filter", n / 2, n),
+        ("Isect cnt 10:1", "Isect flt 10:1", n - small / 2, small),
+    ];
+
+    for (count_name, filter_name, b_start, b_len) in shapes {
+        let hb_set_a: HashSet<i64> = (0..n as i64).collect();
+        let hb_set_b: HashSet<i64> = (b_start..b_start + b_len).map(|i| i as i64).collect();
+        let std_set_a: StdHashSet<i64> = (0..n as i64).collect();
+        let std_set_b: StdHashSet<i64> = (b_start..b_start + b_len).map(|i| i as i64).collect();
+
+        let mut hb_count = Stats::new();
+        let mut hb_filter = Stats::new();
+        let mut std_count = Stats::new();
+        let mut std_filter = Stats::new();
+        let mut counts = [0usize; 4];
+
+        for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+            // hashbrown intersection().count()
+            {
+                let start = Instant::now();
+                let count = hb_set_a.intersection(&hb_set_b).count();
+                let elapsed = start.elapsed().as_nanos();
+                counts[0] = black_box(count);
+                if iter >= WARMUP_ITERATIONS {
+                    hb_count.add(elapsed);
+                }
+            }
+
+            // hashbrown filter(contains).count()
+            {
+                let start = Instant::now();
+                let count = hb_set_a.iter().filter(|&x| hb_set_b.contains(x)).count();
+                let elapsed = start.elapsed().as_nanos();
+                counts[1] = black_box(count);
+                if iter >= WARMUP_ITERATIONS {
+                    hb_filter.add(elapsed);
+                }
+            }
+
+            // std HashSet intersection().count()
+            {
+                let start = Instant::now();
+                let count = std_set_a.intersection(&std_set_b).count();
+                let elapsed = start.elapsed().as_nanos();
+                counts[2] = black_box(count);
+                if iter >= WARMUP_ITERATIONS {
+                    std_count.add(elapsed);
+                }
+            }
+
+            // std HashSet filter(contains).count()
+            {
+                let start = Instant::now();
+                let count = std_set_a.iter().filter(|&x| std_set_b.contains(x)).count();
+                let elapsed = start.elapsed().as_nanos();
+                counts[3] = black_box(count);
+                if iter >= WARMUP_ITERATIONS {
+                    std_filter.add(elapsed);
+                }
+            }
+        }
+
+        if verify() {
+            verify_results_equal(count_name, counts[0], counts[2]);
+            verify_results_equal(filter_name, counts[1], counts[3]);
+            verify_results_equal(count_name, counts[0], counts[1]);
+        }
+
+        print_result(count_name, hb_count.ns_per_op(n), std_count.ns_per_op(n));
+        print_result(filter_name, hb_filter.ns_per_op(n), std_filter.ns_per_op(n));
+    }
+}
+
+// ============================================================================
+// Dedup Benchmarks
+// ============================================================================
+
+/// Dedups a shuffled Vec of n values with 50% duplicates three ways.
+///
+/// The hash variants `retain` the first occurrence of each value, keeping input
+/// order; `sort_unstable` + `dedup` gives up the order. Each run works on an
+/// untimed clone of the input, and results are reported per input element.
+fn bench_vec_dedup(n: usize) {
+    let distinct = (n / 2).max(1);
+    let mut rng = Rng::new(11235);
+    // xorshift never repeats within its period, so the pool values are distinct
+    let pool: Vec<i64> = (0..distinct).map(|_| rng.next() as i64).collect();
+    let mut input: Vec<i64> = (0..n)
+        .map(|i| {
+            pool[if i < distinct {
+                i
+            } else {
+                rng.next_bounded(distinct as u64) as usize
+            }]
+        })
+        .collect();
+    // Fisher-Yates
+    for i in (1..input.len()).rev() {
+        let j = rng.next_bounded(i as u64 + 1) as usize;
+        input.swap(i, j);
+    }
+
+    let mut hb_stats = Stats::new();
+    let mut std_stats = Stats::new();
+    let mut sort_stats = Stats::new();
+    let mut results: [Vec<i64>; 3] = Default::default();
+
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // hashbrown HashSet
+        {
+            let mut v = input.clone();
+            let start = Instant::now();
+            let mut seen: HashSet<i64> = HashSet::new();
+            v.retain(|&x| seen.insert(x));
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&v);
+            if iter >= WARMUP_ITERATIONS {
+                hb_stats.add(elapsed);
+            }
+            results[0] = v;
+        }
+
+        // std HashSet
+        {
+            let mut v = input.clone();
+            let start = Instant::now();
+            let mut seen: StdHashSet<i64> = StdHashSet::new();
+            v.retain(|&x| seen.insert(x));
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&v);
+            if iter >= WARMUP_ITERATIONS {
+                std_stats.add(elapsed);
+            }
+            results[1] = v;
+        }
+
+        // sort_unstable + dedup
+        {
+            let mut v = input.clone();
+            let start = Instant::now();
+            v.sort_unstable();
+            v.dedup();
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&v);
+            if iter >= WARMUP_ITERATIONS {
+                sort_stats.add(elapsed);
+            }
+            results[2] = v;
+        }
+    }
 
-    let hb_bytes_per_entry = hb_mem as f64 / n as f64;
-    let std_bytes_per_entry = std_mem as f64 / n as f64;
+    if verify() {
+        verify_results_equal("Dedup order", &results[0], &results[1]);
+        let mut hb_sorted = results[0].clone();
+        hb_sorted.sort_unstable();
+        verify_results_equal("Dedup (sort)", &hb_sorted, &results[2]);
+    }
+    assert_eq!(results[2].len(), distinct);
+
+    let (hb_ns, std_ns, sort_ns) = (
+        hb_stats.ns_per_op(n),
+        std_stats.ns_per_op(n),
+        sort_stats.ns_per_op(n),
+    );
+    print_result("Dedup (hash)", hb_ns, std_ns);
+    let speedup = sort_ns / hb_ns;
+    let indicator = if speedup >= 1.0 { "+" } else { "-" };
+    println!(
+        "  {:<14} sort  {:>8.1}ns/op   hb vs sort       {}{:.2}x",
+        "Dedup (sort)", sort_ns, indicator, speedup
+    );
+}
+
+// ============================================================================
+// Value Layout Benchmarks
+// ============================================================================
+
+/// 128-byte payload for the inline-vs-boxed comparison.
+#[derive(Clone, Copy)]
+struct BigValue {
+    fields: [u64; 16],
+}
+
+impl BigValue {
+    fn new(seed: u64) -> Self {
+        let mut fields = [0u64; 16];
+        for (i, f) in fields.iter_mut().enumerate() {
+            *f = seed.wrapping_add(i as u64);
+        }
+        BigValue { fields }
+    }
+}
+
+/// Stores a 128-byte value inline and behind a `Box`.
+///
+/// Insert grows from an empty map, so inline values are moved on every resize
+/// while boxed ones only move a pointer (the `Box` allocation is timed too).
+/// Read looks up n random keys and reads one field, so boxed values pay a
+/// pointer chase per hit.
+fn bench_boxed_values(n: usize) {
+    let mut rng = Rng::new(14142);
+    let lookups: Vec<i64> = (0..n).map(|_| rng.next_bounded(n as u64) as i64).collect();
+
+    let mut hb_inline: HashMap<i64, BigValue> = HashMap::new();
+    let mut hb_boxed: HashMap<i64, Box<BigValue>> = HashMap::new();
+    let mut std_inline: StdHashMap<i64, BigValue> = StdHashMap::new();
+    let mut std_boxed: StdHashMap<i64, Box<BigValue>> = StdHashMap::new();
+
+    // Insert
+    let mut insert_stats = [Stats::new(), Stats::new(), Stats::new(), Stats::new()];
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // hashbrown inline
+        {
+            let mut map: HashMap<i64, BigValue> = HashMap::new();
+            let start = Instant::now();
+            for i in 0..n {
+                map.insert(i as i64, BigValue::new(i as u64));
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                insert_stats[0].add(elapsed);
+            }
+            hb_inline = map;
+        }
+
+        // hashbrown boxed
+        {
+            let mut map: HashMap<i64, Box<BigValue>> = HashMap::new();
+            let start = Instant::now();
+            for i in 0..n {
+                map.insert(i as i64, Box::new(BigValue::new(i as u64)));
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                insert_stats[1].add(elapsed);
+            }
+            hb_boxed = map;
+        }
+
+        // std HashMap inline
+        {
+            let mut map: StdHashMap<i64, BigValue> = StdHashMap::new();
+            let start = Instant::now();
+            for i in 0..n {
+                map.insert(i as i64, BigValue::new(i as u64));
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                insert_stats[2].add(elapsed);
+            }
+            std_inline = map;
+        }
+
+        // std HashMap boxed
+        {
+            let mut map: StdHashMap<i64, Box<BigValue>> = StdHashMap::new();
+            let start = Instant::now();
+            for i in 0..n {
+                map.insert(i as i64, Box::new(BigValue::new(i as u64)));
+            }
+            let elapsed = start.elapsed().as_nanos();
+            black_box(&map);
+            if iter >= WARMUP_ITERATIONS {
+                insert_stats[3].add(elapsed);
+            }
+            std_boxed = map;
+        }
+    }
+
+    // Random lookup, read one field
+    let mut read_stats = [Stats::new(), Stats::new(), Stats::new(), Stats::new()];
+    let mut sums = [0u64; 4];
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // hashbrown inline
+        {
+            let mut sum: u64 = 0;
+            let start = Instant::now();
+            for k in &lookups {
+                if let Some(v) = hb_inline.get(k) {
+                    sum = sum.wrapping_add(v.fields[7]);
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            sums[0] = black_box(sum);
+            if iter >= WARMUP_ITERATIONS {
+                read_stats[0].add(elapsed);
+            }
+        }
+
+        // hashbrown boxed
+        {
+            let mut sum: u64 = 0;
+            let start = Instant::now();
+            for k in &lookups {
+                if let Some(v) = hb_boxed.get(k) {
+                    sum = sum.wrapping_add(v.fields[7]);
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            sums[1] = black_box(sum);
+            if iter >= WARMUP_ITERATIONS {
+                read_stats[1].add(elapsed);
+            }
+        }
+
+        // std HashMap inline
+        {
+            let mut sum: u64 = 0;
+            let start = Instant::now();
+            for k in &lookups {
+                if let Some(v) = std_inline.get(k) {
+                    sum = sum.wrapping_add(v.fields[7]);
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            sums[2] = black_box(sum);
+            if iter >= WARMUP_ITERATIONS {
+                read_stats[2].add(elapsed);
+            }
+        }
+
+        // std HashMap boxed
+        {
+            let mut sum: u64 = 0;
+            let start = Instant::now();
+            for k in &lookups {
+                if let Some(v) = std_boxed.get(k) {
+                    sum = sum.wrapping_add(v.fields[7]);
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            sums[3] = black_box(sum);
+            if iter >= WARMUP_ITERATIONS {
+                read_stats[3].add(elapsed);
+            }
+        }
+    }
+
+    if verify() {
+        verify_results_equal("Read (inline)", sums[0], sums[2]);
+        verify_results_equal("Read (boxed)", sums[1], sums[3]);
+        verify_results_equal("Read (boxed)", sums[0], sums[1]);
+    }
+
+    let per_op = |stats: &[Stats; 4], i: usize| stats[i].ns_per_op(n);
+    print_result(
+        "Insert inline",
+        per_op(&insert_stats, 0),
+        per_op(&insert_stats, 2),
+    );
+    print_result(
+        "Insert boxed",
+        per_op(&insert_stats, 1),
+        per_op(&insert_stats, 3),
+    );
+    print_result(
+        "Read (inline)",
+        per_op(&read_stats, 0),
+        per_op(&read_stats, 2),
+    );
+    print_result(
+        "Read (boxed)",
+        per_op(&read_stats, 1),
+        per_op(&read_stats, 3),
+    );
+}
+
+// ============================================================================
+// Memory Usage
+// ============================================================================
+
+/// Load factors assumed by the memory estimate (see `--capacity-report`).
+/// std's `HashMap` wraps hashbrown, so both are 7/8.
+const HB_LOAD_FACTOR: f64 = 0.875;
+const STD_LOAD_FACTOR: f64 = 0.875;
+
+/// Bytes one n-entry `<i64, i64>` table allocates at `load_factor`: the
+/// bucket count is n / load factor rounded up to a power of two, with one
+/// control byte per bucket plus a trailing group of control bytes.
+fn table_bytes(n: usize, load_factor: f64) -> usize {
+    let buckets = ((n as f64 / load_factor) as usize).next_power_of_two();
+    let ctrl_size = buckets + 16; // GROUP_WIDTH padding
+    ctrl_size + buckets * std::mem::size_of::<(i64, i64)>()
+}
+
+/// Estimated (hb, std) bytes per entry for an n-entry `<i64, i64>` map.
+fn estimated_bytes_per_entry(n: usize) -> (f64, f64) {
+    let hb_mem = table_bytes(n, HB_LOAD_FACTOR);
+    let std_mem = table_bytes(n, STD_LOAD_FACTOR);
+    (hb_mem as f64 / n as f64, std_mem as f64 / n as f64)
+}
+
+fn bench_memory_usage(n: usize) {
+    let (hb_bytes_per_entry, std_bytes_per_entry) = estimated_bytes_per_entry(n);
 
     print!(
         "  Memory:      hb    {:>6.1} B/entry   std {:>6.1} B/entry   ",
         hb_bytes_per_entry, std_bytes_per_entry
     );
-    if hb_bytes_per_entry < std_bytes_per_entry {
+    if hb_bytes_per_entry == std_bytes_per_entry {
+        println!("same layout");
+    } else if hb_bytes_per_entry < std_bytes_per_entry {
         println!(
             "{:.0}% smaller",
             (1.0 - hb_bytes_per_entry / std_bytes_per_entry) * 100.0
@@ -926,10 +3768,441 @@ fn bench_memory_usage(n: usize) {
     }
 }
 
+/// Bytes churned through the allocator while building an n-entry map.
+///
+/// Compares growing from empty with reserving up front. A SwissTable resize
+/// allocates a fresh table and frees the old one instead of calling `realloc`,
+/// so the allocation count is the number of table generations. Needs
+/// `--features track-alloc`.
+fn bench_alloc_churn(n: usize) {
+    let hb_grow = {
+        let before = alloc_track::snapshot();
+        let mut map: HashMap<i64, i64> = HashMap::new();
+        for i in 0..n {
+            map.insert(i as i64, i as i64);
+        }
+        black_box(&map);
+        alloc_track::snapshot().since(before)
+    };
+    let hb_reserve = {
+        let before = alloc_track::snapshot();
+        let mut map: HashMap<i64, i64> = HashMap::with_capacity(n);
+        for i in 0..n {
+            map.insert(i as i64, i as i64);
+        }
+        black_box(&map);
+        alloc_track::snapshot().since(before)
+    };
+    let std_grow = {
+        let before = alloc_track::snapshot();
+        let mut map: StdHashMap<i64, i64> = StdHashMap::new();
+        for i in 0..n {
+            map.insert(i as i64, i as i64);
+        }
+        black_box(&map);
+        alloc_track::snapshot().since(before)
+    };
+    let std_reserve = {
+        let before = alloc_track::snapshot();
+        let mut map: StdHashMap<i64, i64> = StdHashMap::with_capacity(n);
+        for i in 0..n {
+            map.insert(i as i64, i as i64);
+        }
+        black_box(&map);
+        alloc_track::snapshot().since(before)
+    };
+
+    let churn = |s: alloc_track::AllocSnapshot| {
+        format!(
+            "{:.2}MB/{}a/{}r",
+            s.bytes as f64 / 1e6,
+            s.allocations,
+            s.reallocations
+        )
+    };
+    println!(
+        "  Alloc churn: grow hb {} std {}   reserve hb {} std {}   (bytes/allocs/reallocs)",
+        churn(hb_grow),
+        churn(std_grow),
+        churn(hb_reserve),
+        churn(std_reserve)
+    );
+}
+
+/// Measures the RSS growth from building maps and compares it with the
+/// `Memory:` line's `estimated_bytes_per_entry`.
+///
+/// Small maps are built in enough copies to cover `RSS_PROBE_ENTRIES` entries so
+/// page granularity doesn't dominate. All copies stay alive until both sides are
+/// measured, so std cannot reuse pages hashbrown just released. Memory the
+/// allocator retained from earlier benchmarks can still make a delta read low.
+fn bench_memory_rss(n: usize) {
+    const RSS_PROBE_ENTRIES: usize = 1_000_000;
+    const DIVERGENCE_WARN: f64 = 0.20;
+
+    let Some(baseline) = mem_probe::resident_bytes() else {
+        println!("  RSS:         not available on this platform");
+        return;
+    };
+
+    let copies = (RSS_PROBE_ENTRIES / n).max(1);
+    let entries = (copies * n) as f64;
+
+    let hb_maps: Vec<HashMap<i64, i64>> = (0..copies)
+        .map(|_| (0..n).map(|i| (i as i64, i as i64)).collect())
+        .collect();
+    let after_hb = mem_probe::resident_bytes().unwrap_or(baseline);
+
+    let std_maps: Vec<StdHashMap<i64, i64>> = (0..copies)
+        .map(|_| (0..n).map(|i| (i as i64, i as i64)).collect())
+        .collect();
+    let after_std = mem_probe::resident_bytes().unwrap_or(after_hb);
+
+    black_box(&hb_maps);
+    black_box(&std_maps);
+
+    let hb_measured = after_hb.saturating_sub(baseline) as f64 / entries;
+    let std_measured = after_std.saturating_sub(after_hb) as f64 / entries;
+    let (hb_estimated, std_estimated) = estimated_bytes_per_entry(n);
+
+    println!(
+        "  RSS delta:   hb    {:>6.1} B/entry   std {:>6.1} B/entry   ({} x {} entries)",
+        hb_measured, std_measured, copies, n
+    );
+
+    for (name, measured, estimated) in [
+        ("hb", hb_measured, hb_estimated),
+        ("std", std_measured, std_estimated),
+    ] {
+        let divergence = (measured - estimated).abs() / estimated;
+        if divergence > DIVERGENCE_WARN {
+            println!(
+                "  warning: {} RSS {:.1} B/entry differs from estimate {:.1} by {:.0}%; \
+                 the memory estimate may be stale",
+                name,
+                measured,
+                estimated,
+                divergence * 100.0
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Experimental (--experimental)
+// ============================================================================
+
+/// Random hit lookups in batches of `PREFETCH_BATCH`, with and without prefetch.
+///
+/// Pass one hashes the batch (and, for `Batch prefetch`, prefetches each home
+/// group and bucket); pass two probes with `raw_entry().from_key_hashed_nocheck`
+/// so the hash is not computed twice. `Batch get` is the same batching without
+/// prefetch, which separates the prefetch effect from the hash-reuse effect.
+/// hashbrown only; both lines are compared against the plain `get` loop.
+fn bench_batched_prefetch(n: usize) {
+    const PREFETCH_BATCH: usize = 8;
+
+    let map: HashMap<i64, i64> = (0..n as i64).map(|i| (i, i)).collect();
+    let Some(locator) = prefetch::BucketLocator::new(&map) else {
+        println!("  Batch prefetch unavailable (bucket layout not recognized)");
+        return;
+    };
+
+    let mut rng = Rng::new(19937);
+    let keys: Vec<i64> = (0..n).map(|_| rng.next_bounded(n as u64) as i64).collect();
+
+    let mut naive_stats = Stats::new();
+    let mut batch_stats = Stats::new();
+    let mut prefetch_stats = Stats::new();
+    let mut naive_result = 0;
+    let mut batch_result = 0;
+    let mut prefetch_result = 0;
+
+    for iter in 0..(WARMUP_ITERATIONS + ITERATIONS) {
+        // naive get loop
+        {
+            let mut checksum: i64 = 0;
+            let start = Instant::now();
+            for k in &keys {
+                if let Some(&v) = map.get(k) {
+                    checksum = checksum.wrapping_add(v);
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            naive_result = black_box(checksum);
+            if iter >= WARMUP_ITERATIONS {
+                naive_stats.add(elapsed);
+            }
+        }
+
+        // batched, prefetch off and on
+        for (prefetch, stats, result) in [
+            (false, &mut batch_stats, &mut batch_result),
+            (true, &mut prefetch_stats, &mut prefetch_result),
+        ] {
+            let mut checksum: i64 = 0;
+            let mut hashes = [0u64; PREFETCH_BATCH];
+            let start = Instant::now();
+            for batch in keys.chunks(PREFETCH_BATCH) {
+                for (h, k) in hashes.iter_mut().zip(batch) {
+                    *h = map.hasher().hash_one(k);
+                    if prefetch {
+                        locator.prefetch(*h);
+                    }
+                }
+                for (&h, k) in hashes.iter().zip(batch) {
+                    if let Some((_, &v)) = map.raw_entry().from_key_hashed_nocheck(h, k) {
+                        checksum = checksum.wrapping_add(v);
+                    }
+                }
+            }
+            let elapsed = start.elapsed().as_nanos();
+            *result = black_box(checksum);
+            if iter >= WARMUP_ITERATIONS {
+                stats.add(elapsed);
+            }
+        }
+    }
+
+    if verify() {
+        verify_results_equal("Batch get", naive_result, batch_result);
+        verify_results_equal("Batch prefetch", naive_result, prefetch_result);
+    }
+
+    let naive_ns = naive_stats.ns_per_op(n);
+    for (name, stats) in [
+        ("Batch get", &batch_stats),
+        ("Batch prefetch", &prefetch_stats),
+    ] {
+        let batch_ns = stats.ns_per_op(n);
+        let speedup = naive_ns / batch_ns;
+        let indicator = if speedup >= 1.0 { "+" } else { "-" };
+        println!(
+            "  {:<14} naive {:>8.1}ns/op   batch {:>8.1}ns/op   {}{:.2}x",
+            name, naive_ns, batch_ns, indicator, speedup
+        );
+    }
+}
+
+// ============================================================================
+// Capacity Growth Report (--capacity-report)
+// ============================================================================
+
+/// One resize observed while inserting keys one at a time.
+struct Resize {
+    /// Entries in the table when the insert that triggered the resize began.
+    len: usize,
+    old_capacity: usize,
+    new_capacity: usize,
+}
+
+/// Inserts 0..n one at a time and records every change of `capacity()`.
+fn growth_sequence(n: usize, mut insert: impl FnMut(i64) -> usize) -> Vec<Resize> {
+    let mut resizes = Vec::new();
+    let mut capacity = 0;
+    for i in 0..n {
+        let new_capacity = insert(i as i64);
+        if new_capacity != capacity {
+            resizes.push(Resize {
+                len: i,
+                old_capacity: capacity,
+                new_capacity,
+            });
+            capacity = new_capacity;
+        }
+    }
+    resizes
+}
+
+/// Prints the capacity sequence and the load each table reached before resizing.
+///
+/// Load is `len / buckets`, with the bucket count taken as the next power of
+/// two of `capacity()`. Returns the load reached before the last resize.
+fn print_growth(label: &str, resizes: &[Resize]) -> f64 {
+    let capacities: Vec<String> = resizes.iter().map(|r| r.new_capacity.to_string()).collect();
+    let loads: Vec<f64> = resizes
+        .iter()
+        .filter(|r| r.old_capacity > 0)
+        .map(|r| r.len as f64 / r.old_capacity.next_power_of_two() as f64)
+        .collect();
+    let load_strs: Vec<String> = loads.iter().map(|l| format!("{:.1}%", l * 100.0)).collect();
+
+    println!("    {:<4} capacity   {}", label, capacities.join(" -> "));
+    println!("    {:<4} max load   {}", "", load_strs.join("  "));
+    loads.last().copied().unwrap_or(0.0)
+}
+
+/// Prints the observed growth policy of both maps for every benchmark size.
+fn capacity_report(sizes: &[usize]) {
+    println!("Capacity growth (inserting 0..N one key at a time):");
+    let mut hb_load = 0.0;
+    let mut std_load = 0.0;
+    for &n in sizes {
+        println!();
+        println!("  N = {}", n);
+
+        let mut hb_map: HashMap<i64, i64> = HashMap::new();
+        let hb = growth_sequence(n, |k| {
+            hb_map.insert(k, k);
+            hb_map.capacity()
+        });
+        drop(hb_map);
+        hb_load = print_growth("hb", &hb);
+
+        let mut std_map: StdHashMap<i64, i64> = StdHashMap::new();
+        let std = growth_sequence(n, |k| {
+            std_map.insert(k, k);
+            std_map.capacity()
+        });
+        drop(std_map);
+        std_load = print_growth("std", &std);
+    }
+
+    println!();
+    println!(
+        "Steady-state max load: hb {:.1}%  std {:.1}%   (memory estimate assumes {:.1}% / {:.1}%)",
+        hb_load * 100.0,
+        std_load * 100.0,
+        HB_LOAD_FACTOR * 100.0,
+        STD_LOAD_FACTOR * 100.0
+    );
+    for (name, observed, assumed) in [
+        ("hb", hb_load, HB_LOAD_FACTOR),
+        ("std", std_load, STD_LOAD_FACTOR),
+    ] {
+        if (observed - assumed).abs() > 0.005 {
+            println!(
+                "  warning: {} load factor constant {:.3} differs from observed {:.3}",
+                name, assumed, observed
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Memory Guard
+// ============================================================================
+
+/// Footprint of one n-entry `<i64, i64>` table, power-of-two bucket rounding
+/// and control bytes included.
+fn map_footprint_bytes(n: usize) -> f64 {
+    table_bytes(n, HB_LOAD_FACTOR) as f64
+}
+
+/// Runs `bench` unless `maps` hb+std table pairs at size n would exceed the budget.
+///
+/// `maps` is the number of tables each implementation holds at peak, so the
+/// estimate is doubled for the side-by-side hb and std copies.
+fn run_guarded(name: &str, n: usize, maps: usize, bench: impl FnOnce()) {
+    run_within_budget(name, map_footprint_bytes(n) * maps as f64, bench);
+}
+
+/// `run_guarded` for a benchmark that grows one table per implementation
+/// from empty: during the final resize the old, half-size table and the new
+/// one are alive together.
+fn run_guarded_growing(name: &str, n: usize, bench: impl FnOnce()) {
+    run_within_budget(name, map_footprint_bytes(n) * 1.5, bench);
+}
+
+/// Runs `bench` unless `bytes` per implementation, doubled for hb and std,
+/// exceeds the budget.
+fn run_within_budget(name: &str, bytes: f64, bench: impl FnOnce()) {
+    let needed_gb = bytes * 2.0 / 1e9;
+    if needed_gb > options().max_memory_gb {
+        println!("  {:<14} skipped (would need ~{:.2} GB)", name, needed_gb);
+        return;
+    }
+    bench();
+}
+
 // ============================================================================
 // Output helpers
 // ============================================================================
 
+/// Prints min/median/max of per-seed samples and the speedup range they imply.
+fn print_spread(name: &str, hb: &mut [f64], std: &mut [f64]) {
+    hb.sort_by(|a, b| a.total_cmp(b));
+    std.sort_by(|a, b| a.total_cmp(b));
+
+    let (hb_min, hb_med, hb_max) = (hb[0], hb[hb.len() / 2], hb[hb.len() - 1]);
+    let (std_min, std_med, std_max) = (std[0], std[std.len() / 2], std[std.len() - 1]);
+
+    println!("  {} across {} seeds:", name, hb.len());
+    println!(
+        "    hb    min {:>8.1}  med {:>8.1}  max {:>8.1} ns/op   spread {:>5.1}%",
+        hb_min,
+        hb_med,
+        hb_max,
+        (hb_max - hb_min) / hb_med * 100.0
+    );
+    println!(
+        "    std   min {:>8.1}  med {:>8.1}  max {:>8.1} ns/op   spread {:>5.1}%",
+        std_min,
+        std_med,
+        std_max,
+        (std_max - std_min) / std_med * 100.0
+    );
+    println!(
+        "    speedup  median {:.2}x   worst {:.2}x   best {:.2}x",
+        std_med / hb_med,
+        std_min / hb_max,
+        std_max / hb_min
+    );
+}
+
+fn print_throughput(name: &str, hb_ops_per_sec: f64, std_ops_per_sec: f64) {
+    let speedup = hb_ops_per_sec / std_ops_per_sec;
+    let indicator = if speedup >= 1.0 { "+" } else { "-" };
+    println!(
+        "  {:<14} hb    {:>7.1}Mops/s   std {:>7.1}Mops/s   {}{:.2}x",
+        name,
+        hb_ops_per_sec / 1e6,
+        std_ops_per_sec / 1e6,
+        indicator,
+        speedup
+    );
+}
+
+/// Splits a lookup result into calibrated hashing cost and table-only cost.
+fn print_hash_share(cost: &HashCost, hb_ns: f64, std_ns: f64) {
+    let hb_table = (hb_ns - cost.hb_ns).max(0.0);
+    let std_table = (std_ns - cost.std_ns).max(0.0);
+    let table_only = if hb_table > 0.0 {
+        format!("{:.2}x", std_table / hb_table)
+    } else {
+        "n/a".to_string()
+    };
+    println!(
+        "  {:<14} hash  {:>8.1}ns/op   hash{:>8.1}ns/op   table-only {}",
+        "  of which", cost.hb_ns, cost.std_ns, table_only
+    );
+}
+
+/// Prints one ns/op column per entry, then the speedup between neighbours.
+fn print_columns(name: &str, columns: &[(&str, f64)]) {
+    let mut line = format!("  {:<14}", name);
+    for (label, ns) in columns {
+        line.push_str(&format!(" {} {:>8.1}ns/op  ", label, ns));
+    }
+    for pair in columns.windows(2) {
+        let speedup = pair[1].1 / pair[0].1;
+        let indicator = if speedup >= 1.0 { "+" } else { "-" };
+        line.push_str(&format!(" {}{:.2}x", indicator, speedup));
+    }
+    println!("{}", line);
+}
+
+/// `print_result`, or the three-column hasher split when `std_fast_ns` is set.
+fn print_result_split(name: &str, hb_ns: f64, std_fast_ns: Option<f64>, std_ns: f64) {
+    match std_fast_ns {
+        Some(fast_ns) => print_columns(
+            name,
+            &[("hb   ", hb_ns), ("std+hb", fast_ns), ("std", std_ns)],
+        ),
+        None => print_result(name, hb_ns, std_ns),
+    }
+}
+
 fn print_result(name: &str, hb_ns: f64, std_ns: f64) {
     let speedup = std_ns / hb_ns;
     let indicator = if speedup >= 1.0 { "+" } else { "-" };
@@ -938,3 +4211,44 @@ fn print_result(name: &str, hb_ns: f64, std_ns: f64) {
         name, hb_ns, std_ns, indicator, speedup
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digest_ignores_insertion_order() {
+        let mut hb: HashMap<i64, i64> = HashMap::new();
+        let mut std: StdHashMap<i64, i64> = StdHashMap::new();
+        for i in 0..1000 {
+            hb.insert(i, i * 3);
+            std.insert(999 - i, (999 - i) * 3);
+        }
+        verify_maps_equal("test", map_digest(&hb), map_digest(&std));
+    }
+
+    #[test]
+    #[should_panic(expected = "verify failed")]
+    fn digest_detects_changed_value() {
+        let hb: HashMap<i64, i64> = (0..100).map(|i| (i, i)).collect();
+        let mut std: StdHashMap<i64, i64> = (0..100).map(|i| (i, i)).collect();
+        std.insert(42, 43);
+        verify_maps_equal("test", map_digest(&hb), map_digest(&std));
+    }
+
+    #[test]
+    #[should_panic(expected = "verify failed")]
+    fn digest_detects_missing_entry() {
+        let hb: HashMap<i64, i64> = (0..100).map(|i| (i, i)).collect();
+        let std: StdHashMap<i64, i64> = (0..99).map(|i| (i, i)).collect();
+        verify_maps_equal("test", map_digest(&hb), map_digest(&std));
+    }
+
+    #[test]
+    #[should_panic(expected = "in hb but not in std")]
+    fn sets_detect_membership_mismatch() {
+        let hb: HashSet<i64> = (0..100).collect();
+        let std: StdHashSet<i64> = (1..101).collect();
+        verify_sets_equal("test", &hb, &std);
+    }
+}
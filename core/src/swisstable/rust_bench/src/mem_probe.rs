@@ -0,0 +1,92 @@
+//! Resident set size (RSS) probe.
+//!
+//! Linux reads `/proc/self/statm`, macOS asks the kernel via `task_info`.
+//! Other platforms return `None` so callers can skip the measurement.
+
+/// Current resident set size of this process in bytes, if the platform exposes it.
+pub fn resident_bytes() -> Option<usize> {
+    imp::resident_bytes()
+}
+
+#[cfg(target_os = "linux")]
+mod imp {
+    use std::os::raw::{c_int, c_long};
+
+    extern "C" {
+        fn sysconf(name: c_int) -> c_long;
+    }
+
+    /// `_SC_PAGESIZE` from <unistd.h>; glibc and musl agree on it.
+    #[cfg(any(target_env = "gnu", target_env = "musl"))]
+    const SC_PAGESIZE: Option<c_int> = Some(30);
+    #[cfg(not(any(target_env = "gnu", target_env = "musl")))]
+    const SC_PAGESIZE: Option<c_int> = None;
+
+    pub fn resident_bytes() -> Option<usize> {
+        // statm: size resident shared text lib data dt (all in pages)
+        let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+        let resident_pages: usize = statm.split_whitespace().nth(1)?.parse().ok()?;
+        let page_size = unsafe { sysconf(SC_PAGESIZE?) };
+        if page_size <= 0 {
+            return None;
+        }
+        Some(resident_pages * page_size as usize)
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod imp {
+    #[repr(C)]
+    #[derive(Default)]
+    struct TimeValue {
+        seconds: i32,
+        microseconds: i32,
+    }
+
+    /// `struct mach_task_basic_info` from <mach/task_info.h>.
+    #[repr(C)]
+    #[derive(Default)]
+    struct MachTaskBasicInfo {
+        virtual_size: u64,
+        resident_size: u64,
+        resident_size_max: u64,
+        user_time: TimeValue,
+        system_time: TimeValue,
+        policy: i32,
+        suspend_count: i32,
+    }
+
+    const MACH_TASK_BASIC_INFO: u32 = 20;
+    const KERN_SUCCESS: i32 = 0;
+
+    extern "C" {
+        /// The task port; `mach_task_self()` is a C macro reading this.
+        static mach_task_self_: u32;
+        fn task_info(task: u32, flavor: u32, info: *mut i32, count: *mut u32) -> i32;
+    }
+
+    pub fn resident_bytes() -> Option<usize> {
+        let mut info = MachTaskBasicInfo::default();
+        let mut count =
+            (std::mem::size_of::<MachTaskBasicInfo>() / std::mem::size_of::<u32>()) as u32;
+        let kr = unsafe {
+            task_info(
+                mach_task_self_,
+                MACH_TASK_BASIC_INFO,
+                &mut info as *mut MachTaskBasicInfo as *mut i32,
+                &mut count,
+            )
+        };
+        if kr != KERN_SUCCESS {
+            return None;
+        }
+        Some(info.resident_size as usize)
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+mod imp {
+    pub fn resident_bytes() -> Option<usize> {
+        None
+    }
+}
@@ -0,0 +1,99 @@
+//! Software prefetch of hashbrown buckets (`--experimental`).
+//!
+//! hashbrown 0.15 no longer exposes `RawTable`, so the bucket layout is
+//! reconstructed from the outside: entries are stored in reverse just below
+//! the control bytes, so bucket `i` lives at `ctrl - (i + 1) * size_of::<(K, V)>()`
+//! and the probe for a hash starts at `hash & (buckets - 1)`. The control
+//! pointer is recovered by assuming most sampled entries sit in their home
+//! bucket and taking the address they agree on. If no address wins a clear
+//! majority, `BucketLocator::new` returns `None` rather than guessing.
+
+use hashbrown::HashMap;
+use std::collections::HashMap as StdHashMap;
+use std::hash::{BuildHasher, Hash};
+use std::mem::{size_of, MaybeUninit};
+
+/// Entries sampled when recovering the control pointer.
+const LOCATE_SAMPLES: usize = 1024;
+
+/// Bucket address math for one table allocation.
+///
+/// Only valid while the map is neither resized nor dropped.
+pub struct BucketLocator {
+    ctrl: usize,
+    bucket_mask: usize,
+    entry_size: usize,
+}
+
+impl BucketLocator {
+    pub fn new<K: Hash, V, S: BuildHasher>(map: &HashMap<K, V, S>) -> Option<Self> {
+        let entry_size = size_of::<(K, V)>();
+        if map.is_empty() || entry_size == 0 {
+            return None;
+        }
+        // capacity() is 7/8 of the bucket count (or buckets - 1 below 8)
+        let bucket_mask = map.capacity().next_power_of_two() - 1;
+        let key_offset = key_offset::<K, V>();
+
+        let mut votes: StdHashMap<usize, usize> = StdHashMap::new();
+        let mut sampled = 0;
+        for (k, _) in map.iter().take(LOCATE_SAMPLES) {
+            let home = map.hasher().hash_one(k) as usize & bucket_mask;
+            let entry = k as *const K as usize - key_offset;
+            *votes.entry(entry + (home + 1) * entry_size).or_insert(0) += 1;
+            sampled += 1;
+        }
+
+        let (ctrl, count) = votes.into_iter().max_by_key(|&(_, c)| c)?;
+        (count * 2 > sampled).then_some(BucketLocator {
+            ctrl,
+            bucket_mask,
+            entry_size,
+        })
+    }
+
+    /// Prefetches the control group and the home bucket for `hash`.
+    #[inline]
+    pub fn prefetch(&self, hash: u64) {
+        let pos = hash as usize & self.bucket_mask;
+        prefetch_read(self.ctrl + pos);
+        prefetch_read(self.ctrl - (pos + 1) * self.entry_size);
+    }
+}
+
+/// Offset of the key inside the `(K, V)` tuple hashbrown stores.
+fn key_offset<K, V>() -> usize {
+    let slot = MaybeUninit::<(K, V)>::uninit();
+    let base = slot.as_ptr();
+    // addr_of! never reads the uninitialized memory
+    let key = unsafe { std::ptr::addr_of!((*base).0) };
+    key as usize - base as usize
+}
+
+#[inline(always)]
+fn prefetch_read(addr: usize) {
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+        _mm_prefetch::<_MM_HINT_T0>(addr as *const i8);
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = addr;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locator_brackets_every_entry() {
+        let map: HashMap<i64, i64> = (0..10_000).map(|i| (i, i)).collect();
+        let loc = BucketLocator::new(&map).expect("layout not recognized");
+        let data_start = loc.ctrl - (loc.bucket_mask + 1) * loc.entry_size;
+        for (k, _) in &map {
+            let entry = k as *const i64 as usize - key_offset::<i64, i64>();
+            assert!((data_start..loc.ctrl).contains(&entry));
+            assert_eq!((loc.ctrl - entry) % loc.entry_size, 0);
+        }
+    }
+}